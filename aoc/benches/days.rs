@@ -0,0 +1,46 @@
+use common::Solution;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Seed of the generated inputs, fixed so runs stay comparable.
+const SEED: u64 = 2021;
+
+/// Times parsing and both parts separately, on the bundled input and on a
+/// generated input roughly ten times its size.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8, bundled: &str, generated: &str) {
+    let mut group = c.benchmark_group(format!("day{}", day));
+
+    for (name, input) in [("bundled", bundled), ("generated", generated)] {
+        group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+            b.iter(|| S::parse(input))
+        });
+
+        let parsed = match S::parse(input) {
+            Ok(parsed) => parsed,
+            Err(error) => panic!("day {} {} input doesn't parse: {}", day, name, error),
+        };
+        group.bench_with_input(BenchmarkId::new("part1", name), &parsed, |b, parsed| {
+            b.iter(|| S::part1(parsed))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &parsed, |b, parsed| {
+            b.iter(|| S::part2(parsed))
+        });
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, 1, day1::INPUT, &day1::generator::generate(SEED, 20000));
+    bench_day::<day2::Day2>(c, 2, day2::INPUT, &day2::generator::generate(SEED, 10000));
+    bench_day::<day3::Day3>(c, 3, day3::INPUT, &day3::generator::generate(SEED, 10000));
+    bench_day::<day4::Day4>(c, 4, day4::INPUT, &day4::generator::generate(SEED, 1000));
+    bench_day::<day5::Day5>(c, 5, day5::INPUT, &day5::generator::generate(SEED, 5000));
+    bench_day::<day6::Day6>(c, 6, day6::INPUT, &day6::generator::generate(SEED, 3000));
+    bench_day::<day7::Day7>(c, 7, day7::INPUT, &day7::generator::generate(SEED, 10000));
+    bench_day::<day8::Day8>(c, 8, day8::INPUT, &day8::generator::generate(SEED, 2000));
+    bench_day::<day9::Day9>(c, 9, day9::INPUT, &day9::generator::generate(SEED, 300));
+    bench_day::<day10::Day10>(c, 10, day10::INPUT, &day10::generator::generate(SEED, 1000));
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,173 @@
+use std::{collections::HashMap, fmt::Display, io, path::Path, str::FromStr};
+
+use common::input::Source;
+
+use crate::{Day, Part};
+
+/// Name of the manifest file that sits next to the inputs it describes.
+pub const MANIFEST_FILE: &str = "answers.txt";
+
+/// Known answers for one input file, `-` in the manifest meaning unknown.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Expected answers per input file, read from lines of the form
+/// `<file> <part1> <part2>`. Blank lines and `#` comments are ignored.
+#[derive(Debug, Default)]
+pub struct Manifest {
+    entries: HashMap<String, Expected>,
+}
+
+impl Manifest {
+    /// Loads the manifest of `dir`, or an empty one if there is none.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(dir.join(MANIFEST_FILE)) {
+            Ok(text) => text
+                .parse()
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn get(&self, file: &str) -> Option<&Expected> {
+        self.entries.get(file)
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = HashMap::new();
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let [file, part1, part2] = fields[..] else {
+                return Err(format!(
+                    "line {}: expected `<file> <part1> <part2>`, found `{}`",
+                    index + 1,
+                    line
+                ));
+            };
+
+            let known = |answer: &str| (answer != "-").then(|| answer.to_string());
+            entries.insert(
+                file.to_string(),
+                Expected {
+                    part1: known(part1),
+                    part2: known(part2),
+                },
+            );
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+/// Looks up the expected answers for `day` solved with the input at `source`.
+/// Bundled inputs use the day's own manifest, files the manifest in their
+/// directory and stdin is never known.
+pub fn expected_for(day: &Day, source: &Source) -> io::Result<Expected> {
+    let (dir, file) = match source {
+        Source::Bundled => (day.input_dir(), format!("day{}.txt", day.number)),
+        Source::File(path) => match path.file_name() {
+            Some(file) => (
+                path.parent().map(Path::to_path_buf).unwrap_or_default(),
+                file.to_string_lossy().into_owned(),
+            ),
+            None => return Ok(Expected::default()),
+        },
+        Source::Stdin => return Ok(Expected::default()),
+    };
+
+    Ok(Manifest::load(&dir)?
+        .get(&file)
+        .cloned()
+        .unwrap_or_default())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn check(answer: &str, expected: Option<&str>) -> Self {
+        match expected {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "FAIL",
+            Verdict::Unknown => "unknown",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest: Manifest = "# input part1 part2\n\nday5.txt 5169 -\n".parse().unwrap();
+
+        let expected = manifest.get("day5.txt").unwrap();
+        assert_eq!(expected.get(Part::One), Some("5169"));
+        assert_eq!(expected.get(Part::Two), None);
+        assert!(manifest.get("day6.txt").is_none());
+    }
+
+    #[test]
+    fn test_parse_manifest_error() {
+        let result = "day5.txt 5169\n".parse::<Manifest>();
+
+        assert_eq!(
+            result.unwrap_err(),
+            "line 1: expected `<file> <part1> <part2>`, found `day5.txt 5169`"
+        );
+    }
+
+    #[test]
+    fn test_bundled_answers() {
+        let day = crate::days::find(1).unwrap();
+        let expected = expected_for(day, &Source::Bundled).unwrap();
+
+        assert_eq!(expected.get(Part::One), Some("1288"));
+        assert_eq!(expected.get(Part::Two), Some("1311"));
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::check("7", Some("7")), Verdict::Pass);
+        assert_eq!(Verdict::check("7", Some("8")), Verdict::Fail);
+        assert_eq!(Verdict::check("7", None), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,37 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::answers::MANIFEST_FILE;
+
+/// Every `.txt` input in `dir`, except the answers manifest, sorted by name.
+pub fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_input = path.extension().is_some_and(|ext| ext == "txt")
+            && path.file_name().is_some_and(|name| name != MANIFEST_FILE);
+        if is_input && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_files() {
+        let day = crate::days::find(5).unwrap();
+
+        let files = input_files(&day.input_dir()).unwrap();
+
+        assert_eq!(files, [day.input_dir().join("day5.txt")]);
+    }
+}
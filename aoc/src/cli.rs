@@ -0,0 +1,643 @@
+use std::path::PathBuf;
+
+use aoc::{client, Part};
+use common::input::Source;
+
+pub const USAGE: &str = "Usage:
+    aoc run --day <N> [--part <1|2>] [--input <PATH|->] [--format <table|json>]
+    aoc run --all [--part <1|2>] [--format <table|json>] [JOBS]
+    aoc verify --day <N> [--part <1|2>] [--input <PATH|->]
+    aoc verify --all [--part <1|2>] [JOBS]
+    aoc batch --day <N> [--part <1|2>] [--dir <DIR>] [JOBS]
+    aoc lint --day <N> [--input <PATH|->]
+    aoc lint --all
+    aoc bench (--day <N> | --all) [--part <1|2>] [--runs <N>] [HISTORY]
+    aoc generate --day <N> [--seed <SEED>] [--size <SIZE>]
+    aoc new --day <N>
+    aoc fetch --day <N> [--output <PATH>] [REMOTE]
+    aoc submit --day <N> --part <1|2> [--answer <ANSWER>] [REMOTE]
+
+JOBS is `--parallel` to use every CPU or `--jobs <N>` to use at most N
+threads. Results are always reported in the same order.
+
+HISTORY is `--history <PATH>` to keep the timings somewhere else than
+bench-history.tsv at the workspace root, `--baseline <COMMIT>` to compare
+against the latest run at that commit instead of the latest run at all,
+`--threshold <PERCENT>` to flag parts slower by more than PERCENT (default
+10) and `--no-record` to compare without adding the run to the history.
+
+REMOTE is `--url <URL>` to use another server than the puzzle site and
+`--session <TOKEN>` for the session cookie, which defaults to $AOC_SESSION.
+
+Built with `--features alloc-stats`, `aoc run` also reports the allocations,
+bytes allocated and peak memory of parsing and of each part.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        days: Selection,
+        parts: Vec<Part>,
+        input: Source,
+        format: Format,
+        jobs: usize,
+    },
+    Verify {
+        days: Selection,
+        parts: Vec<Part>,
+        input: Source,
+        jobs: usize,
+    },
+    Batch {
+        day: u8,
+        parts: Vec<Part>,
+        dir: Option<PathBuf>,
+        jobs: usize,
+    },
+    Lint {
+        days: Selection,
+        input: Source,
+    },
+    Bench {
+        days: Selection,
+        parts: Vec<Part>,
+        runs: usize,
+        history: Option<PathBuf>,
+        baseline: Option<String>,
+        threshold: u32,
+        record: bool,
+    },
+    Generate {
+        day: u8,
+        seed: u64,
+        size: usize,
+    },
+    New {
+        day: u8,
+    },
+    Fetch {
+        day: u8,
+        output: Option<PathBuf>,
+        remote: Remote,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+        answer: Option<String>,
+        remote: Remote,
+    },
+}
+
+/// The server to talk to and the session to talk to it as.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Remote {
+    pub url: String,
+    pub session: Option<String>,
+}
+
+impl Default for Remote {
+    fn default() -> Self {
+        Self {
+            url: client::DEFAULT_URL.to_string(),
+            session: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+pub fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => {
+            let options = parse_selection(args)?;
+            Ok(Command::Run {
+                days: options.days,
+                parts: options.parts,
+                input: options.input,
+                format: options.format,
+                jobs: options.jobs,
+            })
+        }
+        Some("verify") => {
+            let options = parse_selection(args)?;
+            if options.format != Format::Table {
+                return Err("--format is only supported by `run`".to_string());
+            }
+            Ok(Command::Verify {
+                days: options.days,
+                parts: options.parts,
+                input: options.input,
+                jobs: options.jobs,
+            })
+        }
+        Some("batch") => parse_batch(args),
+        Some("lint") => parse_lint(args),
+        Some("bench") => parse_bench(args),
+        Some("generate") => parse_generate(args),
+        Some("new") => parse_new(args),
+        Some("fetch") => parse_fetch(args),
+        Some("submit") => parse_submit(args),
+        Some(other) => Err(format!("unknown command `{}`", other)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+/// The flags shared by `run` and `verify`.
+struct Options {
+    days: Selection,
+    parts: Vec<Part>,
+    input: Source,
+    format: Format,
+    jobs: usize,
+}
+
+fn parse_selection(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut days = None;
+    let mut parts = Part::both().to_vec();
+    let mut input = Source::Bundled;
+    let mut format = Format::Table;
+    let mut jobs = 1;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => days = Some(Selection::All),
+            "--day" => days = Some(Selection::Day(parse_day(args.next())?)),
+            "--part" => parts = vec![parse_part(args.next())?],
+            "--input" => {
+                let path = args.next().ok_or("--input needs a path or `-`")?;
+                input = Source::from_arg(Some(&path));
+            }
+            "--format" => format = parse_format(args.next())?,
+            "--parallel" => jobs = available_threads(),
+            "--jobs" => jobs = parse_jobs(args.next())?,
+            other => return Err(format!("unknown argument `{}`", other)),
+        }
+    }
+
+    let days = days.ok_or("either --day or --all is required")?;
+    if days == Selection::All && input != Source::Bundled {
+        return Err("--input can only be used with a single --day".to_string());
+    }
+
+    Ok(Options {
+        days,
+        parts,
+        input,
+        format,
+        jobs,
+    })
+}
+
+fn parse_batch(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut parts = Part::both().to_vec();
+    let mut dir = None;
+    let mut jobs = 1;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_day(args.next())?),
+            "--part" => parts = vec![parse_part(args.next())?],
+            "--dir" => dir = Some(PathBuf::from(args.next().ok_or("--dir needs a path")?)),
+            "--parallel" => jobs = available_threads(),
+            "--jobs" => jobs = parse_jobs(args.next())?,
+            other => return Err(format!("unknown argument `{}`", other)),
+        }
+    }
+
+    let day = day.ok_or("--day is required")?;
+
+    Ok(Command::Batch {
+        day,
+        parts,
+        dir,
+        jobs,
+    })
+}
+
+fn parse_lint(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days = None;
+    let mut input = Source::Bundled;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => days = Some(Selection::All),
+            "--day" => days = Some(Selection::Day(parse_day(args.next())?)),
+            "--input" => {
+                let path = args.next().ok_or("--input needs a path or `-`")?;
+                input = Source::from_arg(Some(&path));
+            }
+            other => return Err(format!("unknown argument `{}`", other)),
+        }
+    }
+
+    let days = days.ok_or("either --day or --all is required")?;
+    if days == Selection::All && input != Source::Bundled {
+        return Err("--input can only be used with a single --day".to_string());
+    }
+
+    Ok(Command::Lint { days, input })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days = None;
+    let mut parts = Part::both().to_vec();
+    let mut runs = 10;
+    let mut history = None;
+    let mut baseline = None;
+    let mut threshold = 10;
+    let mut record = true;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => days = Some(Selection::All),
+            "--day" => days = Some(Selection::Day(parse_day(args.next())?)),
+            "--part" => parts = vec![parse_part(args.next())?],
+            "--runs" => match parse_number("--runs", args.next())? {
+                0 => return Err("--runs needs at least one run".to_string()),
+                count => runs = count,
+            },
+            "--history" => {
+                history = Some(PathBuf::from(args.next().ok_or("--history needs a path")?))
+            }
+            "--baseline" => baseline = Some(args.next().ok_or("--baseline needs a commit")?),
+            "--threshold" => threshold = parse_number("--threshold", args.next())?,
+            "--no-record" => record = false,
+            other => return Err(format!("unknown argument `{}`", other)),
+        }
+    }
+
+    let days = days.ok_or("either --day or --all is required")?;
+
+    Ok(Command::Bench {
+        days,
+        parts,
+        runs,
+        history,
+        baseline,
+        threshold,
+        record,
+    })
+}
+
+fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = 1000;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_day(args.next())?),
+            "--seed" => seed = parse_number("--seed", args.next())?,
+            "--size" => size = parse_number("--size", args.next())?,
+            other => return Err(format!("unknown argument `{}`", other)),
+        }
+    }
+
+    let day = day.ok_or("--day is required")?;
+
+    Ok(Command::Generate { day, seed, size })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_day(args.next())?),
+            other => return Err(format!("unknown argument `{}`", other)),
+        }
+    }
+
+    let day = day.ok_or("--day is required")?;
+
+    Ok(Command::New { day })
+}
+
+fn parse_fetch(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut output = None;
+    let mut remote = Remote::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_day(args.next())?),
+            "--output" => output = Some(PathBuf::from(args.next().ok_or("--output needs a path")?)),
+            other => parse_remote(&mut remote, other, args.next())?,
+        }
+    }
+
+    let day = day.ok_or("--day is required")?;
+
+    Ok(Command::Fetch {
+        day,
+        output,
+        remote,
+    })
+}
+
+fn parse_submit(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut answer = None;
+    let mut remote = Remote::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_day(args.next())?),
+            "--part" => part = Some(parse_part(args.next())?),
+            "--answer" => answer = Some(args.next().ok_or("--answer needs a value")?),
+            other => parse_remote(&mut remote, other, args.next())?,
+        }
+    }
+
+    let day = day.ok_or("--day is required")?;
+    let part = part.ok_or("--part is required")?;
+
+    Ok(Command::Submit {
+        day,
+        part,
+        answer,
+        remote,
+    })
+}
+
+fn parse_remote(remote: &mut Remote, flag: &str, value: Option<String>) -> Result<(), String> {
+    match flag {
+        "--url" => remote.url = value.ok_or("--url needs a value")?,
+        "--session" => remote.session = Some(value.ok_or("--session needs a value")?),
+        other => return Err(format!("unknown argument `{}`", other)),
+    }
+
+    Ok(())
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", flag))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("invalid {} `{}`", flag, value))
+}
+
+fn parse_day(value: Option<String>) -> Result<u8, String> {
+    let value = value.ok_or("--day needs a value")?;
+
+    value
+        .parse()
+        .map_err(|_| format!("invalid day `{}`", value))
+}
+
+fn parse_part(value: Option<String>) -> Result<Part, String> {
+    match value.as_deref() {
+        Some("1") => Ok(Part::One),
+        Some("2") => Ok(Part::Two),
+        Some(other) => Err(format!("invalid part `{}`", other)),
+        None => Err("--part needs a value".to_string()),
+    }
+}
+
+fn parse_jobs(value: Option<String>) -> Result<usize, String> {
+    match parse_number("--jobs", value)? {
+        0 => Err("--jobs needs at least one thread".to_string()),
+        jobs => Ok(jobs),
+    }
+}
+
+fn available_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, |threads| threads.get())
+}
+
+fn parse_format(value: Option<String>) -> Result<Format, String> {
+    match value.as_deref() {
+        Some("table") => Ok(Format::Table),
+        Some("json") => Ok(Format::Json),
+        Some(other) => Err(format!("invalid format `{}`", other)),
+        None => Err("--format needs a value".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_run_day() {
+        let command = parse("run --day 5 --part 2").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: Selection::Day(5),
+                parts: vec![Part::Two],
+                input: Source::Bundled,
+                format: Format::Table,
+                jobs: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        let command = parse("run --all").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: Selection::All,
+                parts: vec![Part::One, Part::Two],
+                input: Source::Bundled,
+                format: Format::Table,
+                jobs: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_run_input() {
+        let command = parse("run --day 3 --input -").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: Selection::Day(3),
+                parts: vec![Part::One, Part::Two],
+                input: Source::Stdin,
+                format: Format::Table,
+                jobs: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_run_json() {
+        let command = parse("run --all --format json").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Run {
+                days: Selection::All,
+                parts: vec![Part::One, Part::Two],
+                input: Source::Bundled,
+                format: Format::Json,
+                jobs: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let command = parse("verify --all --part 1").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Verify {
+                days: Selection::All,
+                parts: vec![Part::One],
+                input: Source::Bundled,
+                jobs: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_batch() {
+        let command = parse("batch --day 5 --dir inputs/day5 --jobs 3").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Batch {
+                day: 5,
+                parts: vec![Part::One, Part::Two],
+                dir: Some(PathBuf::from("inputs/day5")),
+                jobs: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_lint() {
+        assert_eq!(
+            parse("lint --day 4 --input -").unwrap(),
+            Command::Lint {
+                days: Selection::Day(4),
+                input: Source::Stdin,
+            }
+        );
+        assert!(parse("lint --all --format json").is_err());
+        assert!(parse("lint --day 4 --part 1").is_err());
+        assert!(parse("lint --all --jobs 2").is_err());
+        assert!(parse("lint --all --input -").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let command =
+            parse("bench --day 7 --part 2 --runs 5 --baseline abc1234 --no-record").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Bench {
+                days: Selection::Day(7),
+                parts: vec![Part::Two],
+                runs: 5,
+                history: None,
+                baseline: Some("abc1234".to_string()),
+                threshold: 10,
+                record: false,
+            }
+        );
+        assert!(parse("bench --all --runs 0").is_err());
+    }
+
+    #[test]
+    fn test_parse_parallel() {
+        let Command::Verify { jobs, .. } = parse("verify --all --parallel").unwrap() else {
+            panic!("expected the verify command");
+        };
+
+        assert!(jobs >= 1);
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        let command = parse("generate --day 9 --seed 42 --size 200").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Generate {
+                day: 9,
+                seed: 42,
+                size: 200
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(parse("new --day 11").unwrap(), Command::New { day: 11 });
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        let command = parse("fetch --day 3 --url http://127.0.0.1:8000 --session abc").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Fetch {
+                day: 3,
+                output: None,
+                remote: Remote {
+                    url: "http://127.0.0.1:8000".to_string(),
+                    session: Some("abc".to_string()),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        let command = parse("submit --day 3 --part 2 --answer 230").unwrap();
+
+        assert_eq!(
+            command,
+            Command::Submit {
+                day: 3,
+                part: Part::Two,
+                answer: Some("230".to_string()),
+                remote: Remote::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("run").is_err());
+        assert!(parse("run --day five").is_err());
+        assert!(parse("run --all --part 3").is_err());
+        assert!(parse("walk --all").is_err());
+        assert!(parse("run --all --input day5.txt").is_err());
+        assert!(parse("run --all --format yaml").is_err());
+        assert!(parse("verify --all --format json").is_err());
+        assert!(parse("batch --dir inputs").is_err());
+        assert!(parse("run --all --jobs 0").is_err());
+        assert!(parse("generate --seed 1").is_err());
+        assert!(parse("new").is_err());
+        assert!(parse("fetch --day 3 --session").is_err());
+        assert!(parse("submit --day 3").is_err());
+        assert!(parse("generate --day 1 --size -5").is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::Part;
+
+/// Where the puzzles are served from unless another URL is given.
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Environment variable read for the session token when none is given.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const YEAR: u16 = 2021;
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The server answered with an error status, e.g. 400 for a missing or
+    /// expired session and 404 for a puzzle that isn't unlocked.
+    Status(u16, String),
+    Transport(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Status(status, body) => {
+                write!(f, "server answered {}: {}", status, body.trim())
+            }
+            ClientError::Transport(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => {
+                ClientError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    /// Submitted before the cool-down after a wrong answer ended.
+    TooSoon,
+    /// The part is already solved or still locked.
+    WrongLevel,
+    /// Any other page, kept for the caller to show.
+    Unrecognized(String),
+}
+
+impl Outcome {
+    /// Reads the verdict out of the page returned after submitting.
+    pub fn from_page(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::TooSoon
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unrecognized(page.to_string())
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooSoon => write!(f, "answered too recently, wait before retrying"),
+            Outcome::WrongLevel => write!(f, "part already solved or still locked"),
+            Outcome::Unrecognized(_) => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// Downloads inputs and submits answers as the user owning `session`.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!(
+                    "github.com/t0ggah/advent-of-code-2021 aoc/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+        }
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&format!("{}/input", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .call()?;
+
+        response
+            .into_string()
+            .map_err(|error| ClientError::Transport(error.to_string()))
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome, ClientError> {
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)])?;
+
+        let page = response
+            .into_string()
+            .map_err(|error| ClientError::Transport(error.to_string()))?;
+
+        Ok(Outcome::from_page(&page))
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_from_page() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+
+        assert_eq!(
+            Outcome::from_page(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::from_page(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            Outcome::from_page(&page("You gave an answer too recently.")),
+            Outcome::TooSoon
+        );
+        assert_eq!(
+            Outcome::from_page(&page("You don't seem to be solving the right level.")),
+            Outcome::WrongLevel
+        );
+        assert!(matches!(
+            Outcome::from_page("<html></html>"),
+            Outcome::Unrecognized(_)
+        ));
+    }
+}
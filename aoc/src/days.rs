@@ -0,0 +1,262 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use common::{Lint, Solution};
+
+use crate::memory::{self, Usage};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn both() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Part::One => "1",
+            Part::Two => "2",
+        })
+    }
+}
+
+/// The answers for one input, with how long parsing and each part took and,
+/// with the `alloc-stats` feature, how much memory they used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub parse_memory: Option<Usage>,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub solve_time: Duration,
+    pub memory: Option<Usage>,
+}
+
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    solve: fn(&str, &[Part]) -> Result<Solved, String>,
+    lint: fn(&str) -> Vec<Lint>,
+    generate: fn(u64, usize) -> String,
+}
+
+impl Day {
+    /// Parses `input` once and returns the answers for `parts`, in order, or
+    /// the parse error message.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Solved, String> {
+        (self.solve)(input, parts)
+    }
+
+    /// Every break of the input format, ordered by where it is, without
+    /// solving anything.
+    pub fn lint(&self, input: &str) -> Vec<Lint> {
+        (self.lint)(input)
+    }
+
+    /// Generates a synthetic input of roughly `size` items from `seed`.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(seed, size)
+    }
+
+    /// Directory holding the day's bundled input and its answers manifest.
+    pub fn input_dir(&self) -> PathBuf {
+        self.crate_dir().join("input")
+    }
+
+    /// Directory holding the day's example inputs and their answers manifest.
+    pub fn fixtures_dir(&self) -> PathBuf {
+        self.crate_dir().join("fixtures")
+    }
+
+    fn crate_dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("day{}", self.number))
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Solved, String> {
+    let start = Instant::now();
+    let (input, parse_memory) = memory::measure(|| S::parse(input));
+    let parse_time = start.elapsed();
+    let input = input.map_err(|error| error.to_string())?;
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            // Formatting the answer is left out of its memory
+            let (value, memory) = match part {
+                Part::One => {
+                    let (answer, memory) = memory::measure(|| S::part1(&input));
+                    (answer.to_string(), memory)
+                }
+                Part::Two => {
+                    let (answer, memory) = memory::measure(|| S::part2(&input));
+                    (answer.to_string(), memory)
+                }
+            };
+
+            Answer {
+                part,
+                value,
+                solve_time: start.elapsed(),
+                memory,
+            }
+        })
+        .collect();
+
+    Ok(Solved {
+        parse_time,
+        parse_memory,
+        answers,
+    })
+}
+
+fn lint<S: Solution>(input: &str) -> Vec<Lint> {
+    let mut lints = common::lint::text(input);
+    lints.extend(S::lint(input));
+    lints.sort_by_key(|lint| (lint.location.line, lint.location.column));
+
+    lints
+}
+
+pub static DAYS: [Day; 10] = [
+    Day {
+        number: 1,
+        input: day1::INPUT,
+        solve: solve::<day1::Day1>,
+        lint: lint::<day1::Day1>,
+        generate: day1::generator::generate,
+    },
+    Day {
+        number: 2,
+        input: day2::INPUT,
+        solve: solve::<day2::Day2>,
+        lint: lint::<day2::Day2>,
+        generate: day2::generator::generate,
+    },
+    Day {
+        number: 3,
+        input: day3::INPUT,
+        solve: solve::<day3::Day3>,
+        lint: lint::<day3::Day3>,
+        generate: day3::generator::generate,
+    },
+    Day {
+        number: 4,
+        input: day4::INPUT,
+        solve: solve::<day4::Day4>,
+        lint: lint::<day4::Day4>,
+        generate: day4::generator::generate,
+    },
+    Day {
+        number: 5,
+        input: day5::INPUT,
+        solve: solve::<day5::Day5>,
+        lint: lint::<day5::Day5>,
+        generate: day5::generator::generate,
+    },
+    Day {
+        number: 6,
+        input: day6::INPUT,
+        solve: solve::<day6::Day6>,
+        lint: lint::<day6::Day6>,
+        generate: day6::generator::generate,
+    },
+    Day {
+        number: 7,
+        input: day7::INPUT,
+        solve: solve::<day7::Day7>,
+        lint: lint::<day7::Day7>,
+        generate: day7::generator::generate,
+    },
+    Day {
+        number: 8,
+        input: day8::INPUT,
+        solve: solve::<day8::Day8>,
+        lint: lint::<day8::Day8>,
+        generate: day8::generator::generate,
+    },
+    Day {
+        number: 9,
+        input: day9::INPUT,
+        solve: solve::<day9::Day9>,
+        lint: lint::<day9::Day9>,
+        generate: day9::generator::generate,
+    },
+    Day {
+        number: 10,
+        input: day10::INPUT,
+        solve: solve::<day10::Day10>,
+        lint: lint::<day10::Day10>,
+        generate: day10::generator::generate,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(5).map(|day| day.number), Some(5));
+        assert!(find(0).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = find(1).unwrap();
+
+        let solved = day.solve("199\n200\n208\n", &[Part::One]).unwrap();
+
+        assert_eq!(solved.answers.len(), 1);
+        assert_eq!(solved.answers[0].part, Part::One);
+        assert_eq!(solved.answers[0].value, "2");
+    }
+
+    #[test]
+    fn test_lint() {
+        let day = find(1).unwrap();
+
+        let lints = day.lint("199\r\nabc\n\n");
+
+        assert_eq!(
+            lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+            [
+                "line 1, column 4: line ends with CRLF instead of LF",
+                "line 2, column 1: expected a depth, found `abc`",
+                "line 3, column 1: 1 blank line(s) at the end of the input",
+            ]
+        );
+        assert!(day.lint(day.input).is_empty());
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        let day = find(1).unwrap();
+
+        assert_eq!(
+            day.solve("199\nabc\n", &[Part::One]).unwrap_err(),
+            "line 2, column 1: invalid number `abc`"
+        );
+    }
+}
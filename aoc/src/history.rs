@@ -0,0 +1,320 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::Part;
+
+/// Default name of the history file, kept at the workspace root.
+pub const HISTORY_FILE: &str = "bench-history.tsv";
+
+const HEADER: &str = "# run\tcommit\tday\tstage\tnanos";
+
+/// What was timed: parsing the input or solving one of the parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(part) => part.fmt(f),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "1" => Ok(Stage::Part(Part::One)),
+            "2" => Ok(Stage::Part(Part::Two)),
+            other => Err(format!("invalid stage `{}`", other)),
+        }
+    }
+}
+
+/// One timing of a benchmark run. Every record of a run shares its `run`,
+/// the time it started in milliseconds since the Unix epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub run: u64,
+    pub commit: String,
+    pub day: u8,
+    pub stage: Stage,
+    pub time: Duration,
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.run,
+            self.commit,
+            self.day,
+            self.stage,
+            self.time.as_nanos()
+        )
+    }
+}
+
+/// Every recorded timing, read from tab separated lines of the form
+/// `<run> <commit> <day> <stage> <nanos>`. Blank lines and `#` comments are
+/// ignored.
+#[derive(Debug, Default)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Loads the history at `path`, or an empty one if there is none yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => text
+                .parse()
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Appends `records` to the history at `path`, creating it if needed.
+    pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+        let new = !path.exists();
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+
+        if new {
+            writeln!(file, "{}", HEADER)?;
+        }
+        for record in records {
+            writeln!(file, "{}", record)?;
+        }
+
+        Ok(())
+    }
+
+    /// The records of the latest run made at a commit starting with `commit`,
+    /// or of the latest run at all. Empty if there is no such run.
+    pub fn baseline(&self, commit: Option<&str>) -> Vec<&Record> {
+        let run = self
+            .records
+            .iter()
+            .filter(|record| commit.is_none_or(|commit| record.commit.starts_with(commit)))
+            .map(|record| record.run)
+            .max();
+
+        self.records
+            .iter()
+            .filter(|record| Some(record.run) == run)
+            .collect()
+    }
+}
+
+impl FromStr for History {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut records = Vec::new();
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                format!(
+                    "line {}: expected `<run> <commit> <day> <stage> <nanos>`, found `{}`",
+                    index + 1,
+                    line
+                )
+            };
+            let fields = line.split('\t').collect::<Vec<&str>>();
+            let [run, commit, day, stage, nanos] = fields[..] else {
+                return Err(invalid());
+            };
+
+            records.push(Record {
+                run: run.parse().map_err(|_| invalid())?,
+                commit: commit.to_string(),
+                day: day.parse().map_err(|_| invalid())?,
+                stage: stage.parse().map_err(|_| invalid())?,
+                time: Duration::from_nanos(nanos.parse().map_err(|_| invalid())?),
+            });
+        }
+
+        Ok(Self { records })
+    }
+}
+
+/// The time of one stage in this run next to its time in the baseline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub time: Duration,
+    pub baseline: Option<Duration>,
+}
+
+impl Comparison {
+    /// How much slower this run was, in percent of the baseline.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+        (baseline > 0.0).then(|| (self.time.as_secs_f64() / baseline - 1.0) * 100.0)
+    }
+
+    /// Whether this run was more than `threshold` percent slower.
+    pub fn is_regression(&self, threshold: u32) -> bool {
+        self.change()
+            .is_some_and(|change| change > f64::from(threshold))
+    }
+}
+
+/// Matches every record of `current` with the same day and stage in
+/// `baseline`.
+pub fn compare(current: &[Record], baseline: &[&Record]) -> Vec<Comparison> {
+    current
+        .iter()
+        .map(|record| Comparison {
+            day: record.day,
+            stage: record.stage,
+            time: record.time,
+            baseline: baseline
+                .iter()
+                .find(|old| old.day == record.day && old.stage == record.stage)
+                .map(|old| old.time),
+        })
+        .collect()
+}
+
+/// Milliseconds since the Unix epoch, identifying a new run.
+pub fn run_id() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+/// The short hash of the commit checked out in `dir`, marked `-dirty` when
+/// tracked files have changed since, or `unknown` outside of git.
+pub fn current_commit(dir: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(changes) if !changes.is_empty() => format!("{}-dirty", commit),
+            _ => commit,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(run: u64, commit: &str, day: u8, stage: Stage, micros: u64) -> Record {
+        Record {
+            run,
+            commit: commit.to_string(),
+            day,
+            stage,
+            time: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let history = "# run\tcommit\tday\tstage\tnanos\n\n10\tabc1234\t7\t2\t1500\n"
+            .parse::<History>()
+            .unwrap();
+
+        assert_eq!(
+            history.records,
+            [Record {
+                run: 10,
+                commit: "abc1234".to_string(),
+                day: 7,
+                stage: Stage::Part(Part::Two),
+                time: Duration::from_nanos(1500),
+            }]
+        );
+        assert_eq!(history.records[0].to_string(), "10\tabc1234\t7\t2\t1500");
+        assert_eq!(
+            "10\tabc1234\t7\t3\t1500".parse::<History>().unwrap_err(),
+            "line 1: expected `<run> <commit> <day> <stage> <nanos>`, found `10\tabc1234\t7\t3\t1500`"
+        );
+    }
+
+    #[test]
+    fn test_baseline() {
+        let history = History {
+            records: vec![
+                record(1, "aaa", 1, Stage::Parse, 10),
+                record(1, "aaa", 1, Stage::Part(Part::One), 20),
+                record(2, "bbb", 1, Stage::Parse, 30),
+            ],
+        };
+
+        assert_eq!(history.baseline(None), [&history.records[2]]);
+        assert_eq!(history.baseline(Some("aa")).len(), 2);
+        assert!(history.baseline(Some("ccc")).is_empty());
+    }
+
+    #[test]
+    fn test_compare() {
+        let old = [
+            record(1, "aaa", 7, Stage::Part(Part::One), 100),
+            record(1, "aaa", 7, Stage::Part(Part::Two), 100),
+        ];
+        let current = [
+            record(2, "bbb", 7, Stage::Part(Part::One), 105),
+            record(2, "bbb", 7, Stage::Part(Part::Two), 150),
+            record(2, "bbb", 9, Stage::Parse, 100),
+        ];
+
+        let comparisons = compare(&current, &old.iter().collect::<Vec<_>>());
+
+        assert!(!comparisons[0].is_regression(10));
+        assert!(comparisons[1].is_regression(10));
+        assert_eq!(comparisons[1].change().map(f64::round), Some(50.0));
+        assert_eq!(comparisons[2].baseline, None);
+        assert!(!comparisons[2].is_regression(10));
+    }
+
+    #[test]
+    fn test_append() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.tsv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        History::append(&path, &[record(1, "aaa", 1, Stage::Parse, 1)]).unwrap();
+        History::append(&path, &[record(2, "bbb", 1, Stage::Parse, 2)]).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        let history = History::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(text.matches(HEADER).count(), 1);
+        assert_eq!(history.records.len(), 2);
+        assert_eq!(history.baseline(None)[0].commit, "bbb");
+    }
+}
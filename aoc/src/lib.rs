@@ -0,0 +1,16 @@
+pub mod answers;
+pub mod batch;
+pub mod client;
+pub mod days;
+pub mod history;
+pub mod memory;
+pub mod mock_server;
+pub mod parallel;
+pub mod report;
+pub mod scaffold;
+
+pub use days::{Answer, Day, Part, Solved, DAYS};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
@@ -0,0 +1,622 @@
+mod cli;
+
+use std::{
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc::{
+    answers::{self, Expected, Manifest, Verdict},
+    client::{self, Client, Outcome},
+    days,
+    history::{self, History, Record, Stage},
+    memory::{Bytes, Usage},
+    parallel, report, scaffold, Day, Part, Solved,
+};
+use cli::{Command, Format, Remote, Selection};
+use common::input::{Input, Source};
+
+fn main() {
+    let command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Run {
+            days,
+            parts,
+            input,
+            format,
+            jobs,
+        } => {
+            if !run(select(days), &parts, &input, format, jobs) {
+                std::process::exit(1);
+            }
+        }
+        Command::Verify {
+            days,
+            parts,
+            input,
+            jobs,
+        } => {
+            if !verify(select(days), &parts, &input, jobs) {
+                std::process::exit(1);
+            }
+        }
+        Command::Batch {
+            day,
+            parts,
+            dir,
+            jobs,
+        } => {
+            let day = select(Selection::Day(day))[0];
+            if !batch(day, &parts, dir, jobs) {
+                std::process::exit(1);
+            }
+        }
+        Command::Lint { days, input } => {
+            if !lint(select(days), &input) {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            parts,
+            runs,
+            history,
+            baseline,
+            threshold,
+            record,
+        } => {
+            let history =
+                history.unwrap_or_else(|| scaffold::workspace_root().join(history::HISTORY_FILE));
+            let options = BenchOptions {
+                runs,
+                baseline,
+                threshold,
+                record,
+            };
+            if !bench(select(days), &parts, &history, &options) {
+                std::process::exit(1);
+            }
+        }
+        Command::Generate { day, seed, size } => {
+            let day = select(Selection::Day(day))[0];
+            print!("{}", day.generate(seed, size));
+        }
+        Command::New { day } => new(day),
+        Command::Fetch {
+            day,
+            output,
+            remote,
+        } => fetch(day, output, &remote),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            remote,
+        } => {
+            let answer = answer.unwrap_or_else(|| {
+                let day = select(Selection::Day(day))[0];
+                solve_bundled(day, part)
+            });
+            if !submit(day, part, &answer, &remote) {
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn new(day: u8) {
+    let root = scaffold::workspace_root();
+    match scaffold::create(&root, day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            println!(
+                "\nPut an example in day{0}/fixtures/example.txt and its answers in day{0}/fixtures/answers.txt, then run them with `cargo test -p aoc --test examples day{0}::`.",
+                day
+            );
+        }
+        Err(error) => {
+            eprintln!("error: couldn't create day {}: {}", day, error);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn client(remote: &Remote) -> Client {
+    let session = remote
+        .session
+        .clone()
+        .or_else(|| std::env::var(client::SESSION_VAR).ok())
+        .unwrap_or_else(|| {
+            eprintln!(
+                "error: a session token is needed, pass --session or set ${}",
+                client::SESSION_VAR
+            );
+            std::process::exit(2);
+        });
+
+    Client::new(&remote.url, &session)
+}
+
+/// Downloads the day's input, by default over its bundled input.
+fn fetch(day: u8, output: Option<PathBuf>, remote: &Remote) {
+    let output = output.unwrap_or_else(|| {
+        scaffold::workspace_root()
+            .join(format!("day{}", day))
+            .join("input")
+            .join(format!("day{}.txt", day))
+    });
+
+    let input = client(remote).fetch_input(day).unwrap_or_else(|error| {
+        eprintln!("error: couldn't fetch the input of day {}: {}", day, error);
+        std::process::exit(1);
+    });
+    if let Err(error) = std::fs::write(&output, input) {
+        eprintln!("error: couldn't write {}: {}", output.display(), error);
+        std::process::exit(1);
+    }
+
+    println!("wrote {}", output.display());
+}
+
+fn solve_bundled(day: &Day, part: Part) -> String {
+    match day.solve(day.input, &[part]) {
+        Ok(solved) => solved.answers[0].value.clone(),
+        Err(error) => {
+            eprintln!(
+                "error: day {}: couldn't parse the bundled input: {}",
+                day.number, error
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Submits `answer`, returning whether it was judged correct.
+fn submit(day: u8, part: Part, answer: &str, remote: &Remote) -> bool {
+    match client(remote).submit(day, part, answer) {
+        Ok(outcome) => {
+            println!("day {} part {}, answer {}: {}", day, part, answer, outcome);
+            if let Outcome::Unrecognized(page) = &outcome {
+                eprintln!("{}", page);
+            }
+            outcome == Outcome::Correct
+        }
+        Err(error) => {
+            eprintln!(
+                "error: couldn't submit day {} part {}: {}",
+                day, part, error
+            );
+            false
+        }
+    }
+}
+
+fn select(days: Selection) -> Vec<&'static Day> {
+    match days {
+        Selection::All => days::DAYS.iter().collect(),
+        Selection::Day(number) => match days::find(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("error: day {} is not solved yet", number);
+                std::process::exit(2);
+            }
+        },
+    }
+}
+
+fn read_input(day: &Day, source: &Source) -> Input {
+    match source.clone().read(day.input) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: couldn't read {}: {}", source, error);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Prints the answers of every selected part as a table or as JSON lines,
+/// returning whether all inputs could be parsed. Days are solved on up to
+/// `jobs` threads but always reported in order.
+fn run(days: Vec<&Day>, parts: &[Part], source: &Source, format: Format, jobs: usize) -> bool {
+    let mut parsed_all = true;
+
+    let results = parallel::map(days, jobs, |day| {
+        let input = read_input(day, source);
+        (day, day.solve(&input, parts))
+    });
+
+    if format == Format::Table {
+        println!("{:>3}  {:>4}  Answer", "Day", "Part");
+    }
+    for (day, result) in &results {
+        match result {
+            Ok(solved) if format == Format::Json => {
+                for line in report::json_lines(day.number, solved) {
+                    println!("{}", line);
+                }
+            }
+            Ok(solved) => {
+                for answer in &solved.answers {
+                    println!("{:>3}  {:>4}  {}", day.number, answer.part, answer.value);
+                }
+            }
+            Err(error) => {
+                eprintln!(
+                    "error: day {}: couldn't parse {}: {}",
+                    day.number, source, error
+                );
+                parsed_all = false;
+            }
+        }
+    }
+
+    if format == Format::Table && aoc::memory::ENABLED {
+        print_memory(&results);
+    }
+
+    parsed_all
+}
+
+/// Prints the allocations of parsing and of each part of every solved day.
+fn print_memory(results: &[(&Day, Result<Solved, String>)]) {
+    let row = |day: u8, stage: &dyn Display, usage: Option<Usage>| {
+        let usage = usage.unwrap_or_default();
+        println!(
+            "{:>3}  {:>5}  {:>8}  {:>10}  {:>10}",
+            day,
+            stage,
+            usage.allocations,
+            Bytes(usage.bytes),
+            Bytes(usage.peak)
+        );
+    };
+
+    println!(
+        "\n{:>3}  {:>5}  {:>8}  {:>10}  {:>10}",
+        "Day", "Stage", "Allocs", "Bytes", "Peak"
+    );
+    for (day, solved) in results {
+        if let Ok(solved) = solved {
+            row(day.number, &"parse", solved.parse_memory);
+            for answer in &solved.answers {
+                row(day.number, &answer.part, answer.memory);
+            }
+        }
+    }
+}
+
+/// Solves every selected part and compares it against the answers manifest,
+/// returning whether nothing failed. A day whose input can't be read or
+/// parsed or whose solver panics is reported as failed and the remaining days
+/// still run.
+fn verify(days: Vec<&Day>, parts: &[Part], source: &Source, jobs: usize) -> bool {
+    let mut tally = Tally::default();
+
+    let results = parallel::map(days, jobs, |day| {
+        let expected = answers::expected_for(day, source);
+        let checked = check(day, parts, expected.as_ref().ok(), source);
+        (day, expected, checked)
+    });
+
+    println!(
+        "{:>3}  {:>4}  {:<7}  {:<16}  Expected",
+        "Day", "Part", "Status", "Answer"
+    );
+    for (day, expected, checked) in results {
+        let expected = expected.unwrap_or_else(|error| {
+            eprintln!(
+                "warning: couldn't load answers for day {}: {}",
+                day.number, error
+            );
+            Default::default()
+        });
+        if let Some(error) = &checked.error {
+            eprintln!("error: day {}: {}", day.number, error);
+        }
+
+        for (part, answer, verdict) in checked.rows {
+            println!(
+                "{:>3}  {:>4}  {:<7}  {:<16}  {}",
+                day.number,
+                part,
+                verdict,
+                answer,
+                expected.get(part).unwrap_or("-")
+            );
+            tally.record(&verdict);
+        }
+    }
+
+    println!("\n{}", tally);
+
+    tally.failed == 0
+}
+
+/// Like `verify`, but for every input file in `dir`, defaulting to the day's
+/// own input directory, checked against the manifest found there.
+fn batch(day: &Day, parts: &[Part], dir: Option<PathBuf>, jobs: usize) -> bool {
+    let dir = dir.unwrap_or_else(|| day.input_dir());
+    let files = match aoc::batch::input_files(&dir) {
+        Ok(files) => files,
+        Err(error) => {
+            eprintln!("error: couldn't list {}: {}", dir.display(), error);
+            std::process::exit(1);
+        }
+    };
+    let manifest = Manifest::load(&dir).unwrap_or_else(|error| {
+        eprintln!(
+            "warning: couldn't load answers in {}: {}",
+            dir.display(),
+            error
+        );
+        Default::default()
+    });
+
+    let names = files
+        .iter()
+        .map(|file| file.file_name().unwrap_or_default().to_string_lossy())
+        .collect::<Vec<_>>();
+    let width = names
+        .iter()
+        .map(|name| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let results = parallel::map(files.iter().zip(&names).collect(), jobs, |(file, name)| {
+        let source = Source::File(file.clone());
+        let expected = manifest.get(name).cloned().unwrap_or_default();
+        let checked = check(day, parts, Some(&expected), &source);
+        (name, expected, checked)
+    });
+
+    let mut tally = Tally::default();
+    println!(
+        "{:<width$}  {:>4}  {:<7}  {:<16}  Expected",
+        "File",
+        "Part",
+        "Status",
+        "Answer",
+        width = width
+    );
+    for (name, expected, checked) in results {
+        if let Some(error) = &checked.error {
+            eprintln!("error: day {}: {}", day.number, error);
+        }
+
+        for (part, answer, verdict) in checked.rows {
+            println!(
+                "{:<width$}  {:>4}  {:<7}  {:<16}  {}",
+                name,
+                part,
+                verdict,
+                answer,
+                expected.get(part).unwrap_or("-"),
+                width = width
+            );
+            tally.record(&verdict);
+        }
+    }
+
+    println!("\n{}", tally);
+
+    tally.failed == 0
+}
+
+/// Checks the input of every selected day against its format, reporting every
+/// break with where it is. Returns whether all inputs are clean.
+fn lint(days: Vec<&Day>, source: &Source) -> bool {
+    let mut clean = true;
+
+    for day in days {
+        let input = read_input(day, source);
+        let lints = day.lint(&input);
+        if lints.is_empty() {
+            println!("day {}: {} is clean", day.number, source);
+            continue;
+        }
+
+        clean = false;
+        for lint in &lints {
+            println!("day {}: {}: {}", day.number, source, lint);
+        }
+    }
+
+    clean
+}
+
+struct BenchOptions {
+    runs: usize,
+    baseline: Option<String>,
+    threshold: u32,
+    record: bool,
+}
+
+/// Times every selected part on the bundled inputs, compares the timings with
+/// a baseline run from the history and adds them to it. Returns whether no
+/// part got slower than the threshold allows.
+fn bench(days: Vec<&Day>, parts: &[Part], path: &Path, options: &BenchOptions) -> bool {
+    let history = History::load(path).unwrap_or_else(|error| {
+        eprintln!("error: couldn't read {}: {}", path.display(), error);
+        std::process::exit(1);
+    });
+    let baseline = history.baseline(options.baseline.as_deref());
+    match (baseline.first(), &options.baseline) {
+        (Some(run), _) => println!("Comparing with the run at {}\n", run.commit),
+        (None, Some(commit)) => println!("No run at {} to compare with\n", commit),
+        (None, None) => println!("No earlier run to compare with\n"),
+    }
+
+    let run = history::run_id();
+    let commit = history::current_commit(&scaffold::workspace_root());
+    let mut records = Vec::new();
+    for day in days {
+        let timings = time_day(day, parts, options.runs);
+        records.extend(timings.into_iter().map(|(stage, time)| Record {
+            run,
+            commit: commit.clone(),
+            day: day.number,
+            stage,
+            time,
+        }));
+    }
+
+    let comparisons = history::compare(&records, &baseline);
+    let mut regressions = 0;
+    println!(
+        "{:>3}  {:>5}  {:>10}  {:>10}  {:>8}",
+        "Day", "Stage", "Time", "Baseline", "Change"
+    );
+    for comparison in &comparisons {
+        let regression = comparison.is_regression(options.threshold);
+        regressions += regression as usize;
+        println!(
+            "{:>3}  {:>5}  {:>10}  {:>10}  {:>8}{}",
+            comparison.day,
+            comparison.stage,
+            format!("{:.1?}", comparison.time),
+            comparison
+                .baseline
+                .map_or("-".to_string(), |time| format!("{:.1?}", time)),
+            comparison
+                .change()
+                .map_or("-".to_string(), |change| format!("{:+.1}%", change)),
+            if regression { "  slower" } else { "" }
+        );
+    }
+
+    if options.record {
+        if let Err(error) = History::append(path, &records) {
+            eprintln!("error: couldn't write {}: {}", path.display(), error);
+            std::process::exit(1);
+        }
+        println!("\nRecorded the run at {} in {}", commit, path.display());
+    }
+    if regressions > 0 {
+        println!(
+            "\n{} timings slower than the baseline by more than {}%",
+            regressions, options.threshold
+        );
+    }
+
+    regressions == 0
+}
+
+/// The median time of parsing and of every part over `runs` runs on the
+/// bundled input.
+fn time_day(day: &Day, parts: &[Part], runs: usize) -> Vec<(Stage, Duration)> {
+    let mut times = vec![Vec::with_capacity(runs); parts.len() + 1];
+    for _ in 0..runs {
+        let solved = day.solve(day.input, parts).unwrap_or_else(|error| {
+            eprintln!(
+                "error: day {}: couldn't parse the bundled input: {}",
+                day.number, error
+            );
+            std::process::exit(1);
+        });
+        times[0].push(solved.parse_time);
+        for (i, answer) in solved.answers.iter().enumerate() {
+            times[i + 1].push(answer.solve_time);
+        }
+    }
+
+    let stages = std::iter::once(Stage::Parse).chain(parts.iter().map(|&part| Stage::Part(part)));
+    stages
+        .zip(times)
+        .map(|(stage, mut times)| {
+            times.sort();
+            (stage, times[times.len() / 2])
+        })
+        .collect()
+}
+
+/// The verdict of every part of one input, and why solving failed if it did.
+struct Checked {
+    error: Option<String>,
+    rows: Vec<(Part, String, Verdict)>,
+}
+
+impl Checked {
+    fn failed(parts: &[Part], error: String) -> Self {
+        Checked {
+            error: Some(error),
+            rows: parts
+                .iter()
+                .map(|&part| (part, "<error>".to_string(), Verdict::Fail))
+                .collect(),
+        }
+    }
+}
+
+/// Reads `source`, solves `parts` of it and checks every answer against
+/// `expected`. A read or parse error or a panicking solver fails all parts
+/// with `<error>`.
+fn check(day: &Day, parts: &[Part], expected: Option<&Expected>, source: &Source) -> Checked {
+    let expected = |part| expected.and_then(|expected| expected.get(part));
+
+    let input = match source.clone().read(day.input) {
+        Ok(input) => input,
+        Err(error) => {
+            return Checked::failed(parts, format!("couldn't read {}: {}", source, error));
+        }
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(|| day.solve(&input, parts))) {
+        Ok(Ok(solved)) => Checked {
+            error: None,
+            rows: solved
+                .answers
+                .into_iter()
+                .map(|answer| {
+                    let verdict = Verdict::check(&answer.value, expected(answer.part));
+                    (answer.part, answer.value, verdict)
+                })
+                .collect(),
+        },
+        result => Checked::failed(
+            parts,
+            match result {
+                Ok(Err(error)) => format!("couldn't parse {}: {}", source, error),
+                _ => format!("solver panicked on {}", source),
+            },
+        ),
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+}
+
+impl Tally {
+    fn record(&mut self, verdict: &Verdict) {
+        match verdict {
+            Verdict::Pass => self.passed += 1,
+            Verdict::Fail => self.failed += 1,
+            Verdict::Unknown => self.unknown += 1,
+        }
+    }
+}
+
+impl Display for Tally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unknown",
+            self.passed, self.failed, self.unknown
+        )
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+};
+
+/// Heap usage while running a piece of code on one thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Most memory live at once on top of what was live before.
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    live: u64,
+    peak: u64,
+}
+
+thread_local! {
+    // Counted per thread so days solved in parallel don't see each other's
+    // allocations. A const `Cell` needs no allocation to initialise.
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+/// Global allocator that counts what every thread allocates before handing
+/// the work to the system allocator. Installed by the `alloc-stats` feature.
+pub struct Counting;
+
+impl Counting {
+    fn record(allocated: usize, freed: usize) {
+        // Thread locals may already be gone while a thread shuts down
+        let _ = COUNTS.try_with(|counts| {
+            let mut current = counts.get();
+            if allocated > 0 {
+                current.allocations += 1;
+                current.bytes += allocated as u64;
+            }
+            // Memory can be freed on another thread than the one that
+            // allocated it
+            current.live = (current.live + allocated as u64).saturating_sub(freed as u64);
+            current.peak = current.peak.max(current.live);
+            counts.set(current);
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Whether the counting allocator is installed.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Runs `f` and returns its result with the heap usage of the current thread
+/// meanwhile, or `None` when the counting allocator isn't installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let before = COUNTS.with(|counts| {
+        let mut before = counts.get();
+        before.peak = before.live;
+        counts.set(before);
+        before
+    });
+    let result = f();
+    let after = COUNTS.with(Cell::get);
+
+    let usage = Usage {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: after.peak - before.live,
+    };
+    (result, Some(usage))
+}
+
+/// Formats a number of bytes with a binary unit, like `1.5 KiB`.
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return f.pad(&format!("{} B", self.0));
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit + 1 < UNITS.len() {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        f.pad(&format!("{:.1} {}", value, UNITS[unit]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(0).to_string(), "0 B");
+        assert_eq!(Bytes(1023).to_string(), "1023 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.0 MiB");
+        assert_eq!(format!("{:>9}", Bytes(2048)), "  2.0 KiB");
+    }
+
+    #[test]
+    fn test_measure() {
+        let (vec, usage) = measure(|| {
+            let scratch = vec![0u8; 4096];
+            drop(scratch);
+            vec![0u8; 100]
+        });
+
+        assert_eq!(vec.len(), 100);
+        assert_eq!(usage.is_some(), ENABLED);
+        if let Some(usage) = usage {
+            assert_eq!(usage.allocations, 2);
+            assert_eq!(usage.bytes, 4196);
+            assert_eq!(usage.peak, 4096);
+        }
+    }
+}
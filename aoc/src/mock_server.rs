@@ -0,0 +1,286 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{Expected, Manifest},
+    Part, DAYS,
+};
+
+/// A puzzle as the stand-in server knows it: the input it hands out and the
+/// answers it accepts.
+#[derive(Debug, Clone, Default)]
+pub struct Puzzle {
+    pub input: String,
+    pub expected: Expected,
+}
+
+impl Puzzle {
+    /// Every day's bundled input with the answers from its manifest.
+    pub fn bundled() -> io::Result<HashMap<u8, Puzzle>> {
+        DAYS.iter()
+            .map(|day| {
+                let file = format!("day{}.txt", day.number);
+                let expected = Manifest::load(&day.input_dir())?
+                    .get(&file)
+                    .cloned()
+                    .unwrap_or_default();
+                let puzzle = Puzzle {
+                    input: day.input.to_string(),
+                    expected,
+                };
+
+                Ok((day.number, puzzle))
+            })
+            .collect()
+    }
+}
+
+/// A local stand-in for the puzzle site, speaking just enough HTTP to serve
+/// inputs and judge answers for one session. It stops when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+struct State {
+    session: String,
+    puzzles: HashMap<u8, Puzzle>,
+    cooldown: Duration,
+    solved: HashSet<(u8, Part)>,
+    locked_until: HashMap<u8, Instant>,
+}
+
+impl MockServer {
+    /// Listens on a free local port. A wrong answer locks the day's
+    /// submissions for `cooldown`, like the real site does.
+    pub fn start(
+        session: &str,
+        puzzles: HashMap<u8, Puzzle>,
+        cooldown: Duration,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+        let state = Mutex::new(State {
+            session: session.to_string(),
+            puzzles,
+            cooldown,
+            solved: HashSet::new(),
+            locked_until: HashMap::new(),
+        });
+
+        let stopped = Arc::clone(&stop);
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    // A client hanging up mid-request only affects itself
+                    let _ = serve(stream, &state);
+                }
+            }
+        });
+
+        Ok(Self {
+            addr,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Base URL to hand to the client instead of the real site.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Wake up the accepting thread so it sees the flag
+        let _ = TcpStream::connect(self.addr);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    cookie: String,
+    body: String,
+}
+
+fn serve(stream: TcpStream, state: &Mutex<State>) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let request = read_request(&mut BufReader::new(&stream))?;
+
+    let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+    let (status, body) = respond(&request, &mut state);
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+fn read_request(reader: &mut impl BufRead) -> io::Result<Request> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut words = line.split_whitespace();
+    let method = words.next().unwrap_or_default().to_string();
+    let path = words.next().unwrap_or_default().to_string();
+
+    let (mut cookie, mut length) = (String::new(), 0);
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "cookie" => cookie = value.trim().to_string(),
+                "content-length" => length = value.trim().parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn respond(request: &Request, state: &mut State) -> (&'static str, String) {
+    let session = format!("session={}", state.session);
+    if !request.cookie.split(';').any(|c| c.trim() == session) {
+        return (
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".to_string(),
+        );
+    }
+
+    let route = request
+        .path
+        .strip_prefix("/2021/day/")
+        .and_then(|rest| rest.split_once('/'))
+        .and_then(|(day, action)| Some((day.parse::<u8>().ok()?, action)));
+    let not_found = ("404 Not Found", "404 Not Found\n".to_string());
+
+    match (request.method.as_str(), route) {
+        ("GET", Some((day, "input"))) => match state.puzzles.get(&day) {
+            Some(puzzle) => ("200 OK", puzzle.input.clone()),
+            None => not_found,
+        },
+        ("POST", Some((day, "answer"))) if state.puzzles.contains_key(&day) => {
+            ("200 OK", judge(state, day, &request.body))
+        }
+        _ => not_found,
+    }
+}
+
+fn judge(state: &mut State, day: u8, form: &str) -> String {
+    let field = |name: &str| {
+        form.split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| decode(value))
+    };
+    let part = match field("level").as_deref() {
+        Some("1") => Part::One,
+        Some("2") => Part::Two,
+        _ => return "You don't seem to be solving the right level.".to_string(),
+    };
+    let answer = field("answer").unwrap_or_default();
+
+    let solved_before = part == Part::One || state.solved.contains(&(day, Part::One));
+    if state.solved.contains(&(day, part)) || !solved_before {
+        return "You don't seem to be solving the right level.  Did you already complete it?"
+            .to_string();
+    }
+    if let Some(until) = state.locked_until.get(&day) {
+        if Instant::now() < *until {
+            return "You gave an answer too recently; you have to wait after submitting an answer before trying again.".to_string();
+        }
+    }
+
+    if state.puzzles[&day].expected.get(part) == Some(answer.trim()) {
+        state.solved.insert((day, part));
+        "That's the right answer!  You are one gold star closer to finding the sleigh keys."
+            .to_string()
+    } else {
+        state
+            .locked_until
+            .insert(day, Instant::now() + state.cooldown);
+        "That's not the right answer.  Please wait before trying again.".to_string()
+    }
+}
+
+/// Decodes a form value, where `+` is a space and `%XX` an escaped byte.
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_puzzles() {
+        let puzzles = Puzzle::bundled().unwrap();
+
+        assert_eq!(puzzles.len(), DAYS.len());
+        assert_eq!(puzzles[&1].expected.get(Part::One), Some("1288"));
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("1288"), "1288");
+        assert_eq!(decode("a+b%2Cc"), "a b,c");
+        assert_eq!(decode("100%"), "100%");
+    }
+}
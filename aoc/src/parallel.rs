@@ -0,0 +1,82 @@
+use std::{
+    sync::{mpsc, Mutex},
+    thread,
+};
+
+/// Applies `f` to every item on at most `threads` threads and returns the
+/// results in the order of `items`, however the work was interleaved.
+pub fn map<T, R, F>(items: Vec<T>, threads: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let threads = threads.min(items.len());
+    if threads <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            let sender = sender.clone();
+            let (queue, f) = (&queue, &f);
+            scope.spawn(move || loop {
+                // Taking the next item must not hold the lock while solving
+                let next = queue.lock().unwrap_or_else(|e| e.into_inner()).next();
+                match next {
+                    Some((index, item)) => {
+                        let _ = sender.send((index, f(item)));
+                    }
+                    None => break,
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    // A panicking `f` has already been propagated by the scope
+    let mut results = receiver.into_iter().collect::<Vec<(usize, R)>>();
+    results.sort_by_key(|(index, _)| *index);
+
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items = (0..20u64).collect::<Vec<u64>>();
+
+        let results = map(items, 4, |item| {
+            // Later items finish first
+            thread::sleep(Duration::from_millis(20 - item));
+            item * 2
+        });
+
+        assert_eq!(results, (0..20).map(|item| item * 2).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn test_map_caps_threads() {
+        let running = Mutex::new((0, 0));
+
+        map((0..16).collect(), 3, |_: u32| {
+            {
+                let mut running = running.lock().unwrap();
+                running.0 += 1;
+                running.1 = running.1.max(running.0);
+            }
+            thread::sleep(Duration::from_millis(5));
+            running.lock().unwrap().0 -= 1;
+        });
+
+        assert!(running.into_inner().unwrap().1 <= 3);
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Write;
+
+use crate::{
+    days::{Answer, Solved},
+    memory::Usage,
+};
+
+/// One JSON object per answer, each on its own line, with the times in
+/// nanoseconds. Parsing is shared by both parts so they repeat its time, and
+/// its memory when the allocations were counted.
+pub fn json_lines(day: u8, solved: &Solved) -> Vec<String> {
+    solved
+        .answers
+        .iter()
+        .map(|answer| json_line(day, solved, answer))
+        .collect()
+}
+
+fn json_line(day: u8, solved: &Solved, answer: &Answer) -> String {
+    let mut line = format!(
+        r#"{{"day":{},"part":{},"answer":"{}","parse_time_ns":{},"solve_time_ns":{}"#,
+        day,
+        answer.part,
+        escape(&answer.value),
+        solved.parse_time.as_nanos(),
+        answer.solve_time.as_nanos()
+    );
+    if let Some(usage) = solved.parse_memory {
+        push_usage(&mut line, "parse_", usage);
+    }
+    if let Some(usage) = answer.memory {
+        push_usage(&mut line, "", usage);
+    }
+    line.push('}');
+
+    line
+}
+
+fn push_usage(line: &mut String, prefix: &str, usage: Usage) {
+    let _ = write!(
+        line,
+        r#","{0}allocations":{1},"{0}bytes":{2},"{0}peak_bytes":{3}"#,
+        prefix, usage.allocations, usage.bytes, usage.peak
+    );
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Part;
+
+    #[test]
+    fn test_json_lines() {
+        let solved = Solved {
+            parse_time: Duration::from_micros(3),
+            parse_memory: None,
+            answers: vec![Answer {
+                part: Part::Two,
+                value: "say \"hi\"\n".to_string(),
+                solve_time: Duration::from_nanos(42),
+                memory: None,
+            }],
+        };
+
+        assert_eq!(
+            json_lines(7, &solved),
+            [
+                r#"{"day":7,"part":2,"answer":"say \"hi\"\n","parse_time_ns":3000,"solve_time_ns":42}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_lines_with_memory() {
+        let usage = |allocations, bytes, peak| Usage {
+            allocations,
+            bytes,
+            peak,
+        };
+        let solved = Solved {
+            parse_time: Duration::from_nanos(5),
+            parse_memory: Some(usage(3, 120, 100)),
+            answers: vec![Answer {
+                part: Part::One,
+                value: "7".to_string(),
+                solve_time: Duration::from_nanos(9),
+                memory: Some(usage(0, 0, 0)),
+            }],
+        };
+
+        assert_eq!(
+            json_lines(1, &solved),
+            [concat!(
+                r#"{"day":1,"part":1,"answer":"7","parse_time_ns":5,"solve_time_ns":9,"#,
+                r#""parse_allocations":3,"parse_bytes":120,"parse_peak_bytes":100,"#,
+                r#""allocations":0,"bytes":0,"peak_bytes":0}"#
+            )]
+        );
+    }
+}
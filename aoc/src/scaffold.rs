@@ -0,0 +1,264 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+const TEMPLATES: [(&str, &str); 6] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    (
+        "src/generator.rs",
+        include_str!("../templates/generator.rs.tmpl"),
+    ),
+    (
+        "input/answers.txt",
+        include_str!("../templates/answers.txt.tmpl"),
+    ),
+    (
+        "fixtures/answers.txt",
+        include_str!("../templates/fixtures.txt.tmpl"),
+    ),
+];
+
+/// Root of the workspace the `aoc` crate belongs to.
+pub fn workspace_root() -> PathBuf {
+    let aoc = Path::new(env!("CARGO_MANIFEST_DIR"));
+    aoc.parent().unwrap_or(aoc).to_path_buf()
+}
+
+/// Creates the `day<N>` crate under `root` with an empty input and an empty
+/// example fixture, then adds it to the workspace members and to the days
+/// `aoc` runs, so its examples run with the others. Returns the files that
+/// were written.
+pub fn create(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let name = format!("day{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    // Every edit is worked out before anything is written
+    let invalid = |error| io::Error::new(io::ErrorKind::InvalidData, error);
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = register(&std::fs::read_to_string(&manifest_path)?, &name).map_err(invalid)?;
+    let dependencies_path = root.join("aoc").join("Cargo.toml");
+    let dependencies =
+        depend(&std::fs::read_to_string(&dependencies_path)?, &name).map_err(invalid)?;
+    let days_path = root.join("aoc").join("src").join("days.rs");
+    let days = add_day(&std::fs::read_to_string(&days_path)?, day).map_err(invalid)?;
+
+    let mut written = Vec::new();
+    let empty = [
+        (format!("input/{}.txt", name), String::new()),
+        ("fixtures/example.txt".to_string(), String::new()),
+    ];
+    let files = TEMPLATES
+        .iter()
+        .map(|(path, template)| {
+            (
+                path.to_string(),
+                template.replace("{{day}}", &day.to_string()),
+            )
+        })
+        .chain(empty);
+    for (path, contents) in files {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap_or(&dir))?;
+        std::fs::write(&path, contents)?;
+        written.push(path);
+    }
+
+    for (path, contents) in [
+        (manifest_path, manifest),
+        (dependencies_path, dependencies),
+        (days_path, days),
+    ] {
+        std::fs::write(&path, contents)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Adds `member` at the end of the `members` list of a workspace manifest.
+fn register(manifest: &str, member: &str) -> Result<String, String> {
+    let entry = format!("\"{}\"", member);
+    let start = manifest
+        .find("members = [")
+        .ok_or("the workspace manifest has no `members = [` list")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("the workspace `members` list isn't closed")?;
+
+    if manifest[start..end].contains(&entry) {
+        return Err(format!("{} is already a workspace member", member));
+    }
+
+    Ok(format!(
+        "{}    {},\n{}",
+        &manifest[..end],
+        entry,
+        &manifest[end..]
+    ))
+}
+
+/// Adds a path dependency on `member` after the last day `aoc` depends on.
+fn depend(manifest: &str, member: &str) -> Result<String, String> {
+    let entry = format!("{} = {{ path = \"../{}\" }}\n", member, member);
+    if manifest
+        .lines()
+        .any(|line| line.starts_with(&format!("{} =", member)))
+    {
+        return Err(format!("aoc already depends on {}", member));
+    }
+
+    let last = manifest
+        .match_indices("\nday")
+        .last()
+        .ok_or("aoc/Cargo.toml doesn't depend on any day")?
+        .0
+        + 1;
+    let end = last
+        + manifest[last..]
+            .find('\n')
+            .map_or(manifest.len() - last, |i| i + 1);
+
+    Ok(format!("{}{}{}", &manifest[..end], entry, &manifest[end..]))
+}
+
+/// Adds day `day` at the end of `DAYS` in `aoc/src/days.rs`.
+fn add_day(source: &str, day: u8) -> Result<String, String> {
+    const DAYS: &str = "pub static DAYS: [Day; ";
+
+    let start = source
+        .find(DAYS)
+        .ok_or("aoc/src/days.rs has no `DAYS` array")?
+        + DAYS.len();
+    let length_end = start + source[start..].find(']').ok_or("`DAYS` has no length")?;
+    let length = source[start..length_end]
+        .parse::<usize>()
+        .map_err(|_| "`DAYS` has no length")?;
+    let end = length_end
+        + source[length_end..]
+            .find("\n];")
+            .ok_or("the `DAYS` array isn't closed")?
+        + 1;
+
+    if source[length_end..end].contains(&format!("number: {},", day)) {
+        return Err(format!("day {} is already in `DAYS`", day));
+    }
+
+    let entry = [
+        "    Day {".to_string(),
+        format!("        number: {},", day),
+        format!("        input: day{}::INPUT,", day),
+        format!("        solve: solve::<day{0}::Day{0}>,", day),
+        format!("        lint: lint::<day{0}::Day{0}>,", day),
+        format!("        generate: day{}::generator::generate,", day),
+        "    },\n".to_string(),
+    ]
+    .join("\n");
+
+    Ok(format!(
+        "{}{}{}{}",
+        &source[..start],
+        length + 1,
+        &source[length_end..end],
+        entry
+    ) + &source[end..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str =
+        "[workspace]\nresolver = \"2\"\nmembers = [\n    \"common\",\n    \"day1\",\n]\n";
+
+    const AOC_MANIFEST: &str = "[dependencies]\ncommon = { path = \"../common\" }\nday1 = { path = \"../day1\" }\nureq = \"2\"\n";
+
+    const DAYS: &str = "pub static DAYS: [Day; 1] = [\n    Day {\n        number: 1,\n    },\n];\n\npub fn find() {}\n";
+
+    #[test]
+    fn test_register() {
+        let manifest = register(MANIFEST, "day2").unwrap();
+
+        assert_eq!(
+            manifest,
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"common\",\n    \"day1\",\n    \"day2\",\n]\n"
+        );
+        assert!(register(&manifest, "day2").is_err());
+    }
+
+    #[test]
+    fn test_depend() {
+        let manifest = depend(AOC_MANIFEST, "day2").unwrap();
+
+        assert_eq!(
+            manifest,
+            "[dependencies]\ncommon = { path = \"../common\" }\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\nureq = \"2\"\n"
+        );
+        assert!(depend(&manifest, "day2").is_err());
+    }
+
+    #[test]
+    fn test_add_day() {
+        let days = add_day(DAYS, 2).unwrap();
+
+        assert_eq!(
+            days,
+            [
+                "pub static DAYS: [Day; 2] = [",
+                "    Day {",
+                "        number: 1,",
+                "    },",
+                "    Day {",
+                "        number: 2,",
+                "        input: day2::INPUT,",
+                "        solve: solve::<day2::Day2>,",
+                "        lint: lint::<day2::Day2>,",
+                "        generate: day2::generator::generate,",
+                "    },",
+                "];",
+                "",
+                "pub fn find() {}",
+                "",
+            ]
+            .join("\n")
+        );
+        assert!(add_day(&days, 2).is_err());
+        assert!(add_day("", 2).is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        std::fs::create_dir_all(root.join("aoc/src")).unwrap();
+        std::fs::write(root.join("aoc/Cargo.toml"), AOC_MANIFEST).unwrap();
+        std::fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+
+        let written = create(&root, 11).unwrap();
+        let lib = std::fs::read_to_string(root.join("day11/src/lib.rs")).unwrap();
+        let workspace = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        let aoc = std::fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        let days = std::fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        let again = create(&root, 11);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(written.len(), 11);
+        assert!(lib.contains("pub struct Day11;"));
+        assert!(lib.contains("include_str!(\"../input/day11.txt\")"));
+        assert!(workspace.contains("    \"day11\",\n]"));
+        assert!(aoc.contains("day11 = { path = \"../day11\" }\nureq"));
+        assert!(days.contains("solve: solve::<day11::Day11>,"));
+        assert_eq!(again.unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    }
+}
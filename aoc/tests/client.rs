@@ -0,0 +1,103 @@
+//! The fetch and submit workflow, run against the local stand-in server.
+
+use std::{collections::HashMap, process::Command, time::Duration};
+
+use aoc::{
+    answers::Expected,
+    client::{Client, ClientError, Outcome},
+    mock_server::{MockServer, Puzzle},
+    Part,
+};
+
+const SESSION: &str = "53616c7465645f5f";
+
+fn example_server(cooldown: Duration) -> MockServer {
+    let puzzle = Puzzle {
+        input: "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n".to_string(),
+        expected: Expected {
+            part1: Some("7".to_string()),
+            part2: Some("5".to_string()),
+        },
+    };
+
+    MockServer::start(SESSION, HashMap::from([(1, puzzle)]), cooldown).unwrap()
+}
+
+#[test]
+fn test_fetch_solve_and_submit() {
+    let server = example_server(Duration::ZERO);
+    let client = Client::new(&server.url(), SESSION);
+    let day = aoc::days::find(1).unwrap();
+
+    let input = client.fetch_input(1).unwrap();
+    let solved = day.solve(&input, &Part::both()).unwrap();
+
+    assert_eq!(
+        client.submit(1, Part::Two, "5").unwrap(),
+        Outcome::WrongLevel
+    );
+    assert_eq!(
+        client.submit(1, Part::One, "8").unwrap(),
+        Outcome::Incorrect
+    );
+    for answer in &solved.answers {
+        assert_eq!(
+            client.submit(1, answer.part, &answer.value).unwrap(),
+            Outcome::Correct
+        );
+    }
+    assert_eq!(
+        client.submit(1, Part::One, "7").unwrap(),
+        Outcome::WrongLevel
+    );
+}
+
+#[test]
+fn test_cooldown_after_wrong_answer() {
+    let server = example_server(Duration::from_secs(60));
+    let client = Client::new(&server.url(), SESSION);
+
+    assert_eq!(
+        client.submit(1, Part::One, "6").unwrap(),
+        Outcome::Incorrect
+    );
+    assert_eq!(client.submit(1, Part::One, "7").unwrap(), Outcome::TooSoon);
+}
+
+#[test]
+fn test_errors() {
+    let server = example_server(Duration::ZERO);
+
+    let logged_out = Client::new(&server.url(), "expired").fetch_input(1);
+    let locked = Client::new(&server.url(), SESSION).fetch_input(2);
+
+    assert!(matches!(logged_out, Err(ClientError::Status(400, _))));
+    assert!(matches!(locked, Err(ClientError::Status(404, _))));
+}
+
+#[test]
+fn test_cli_fetch_and_submit() {
+    let server = example_server(Duration::ZERO);
+    let output = std::env::temp_dir().join(format!("aoc-fetch-{}.txt", std::process::id()));
+    let aoc = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(args)
+            .args(["--url", &server.url(), "--session", SESSION])
+            .output()
+            .unwrap()
+    };
+
+    let fetched = aoc(&["fetch", "--day", "1", "--output", output.to_str().unwrap()]);
+    let input = std::fs::read_to_string(&output).unwrap();
+    std::fs::remove_file(&output).unwrap();
+    let wrong = aoc(&["submit", "--day", "1", "--part", "1", "--answer", "6"]);
+    let right = aoc(&["submit", "--day", "1", "--part", "1", "--answer", "7"]);
+
+    assert!(fetched.status.success());
+    assert!(input.starts_with("199\n200\n"));
+    assert!(!wrong.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&right.stdout),
+        "day 1 part 1, answer 7: correct\n"
+    );
+}
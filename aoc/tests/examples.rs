@@ -0,0 +1,65 @@
+//! Runs every example in `dayN/fixtures` against the answers manifest next
+//! to it, one test per input and part. Examples without a known answer for a
+//! part are ignored.
+
+use aoc::{answers::Manifest, batch::input_files, Day, Part, DAYS};
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let arguments = Arguments::from_args();
+
+    let trials = DAYS
+        .iter()
+        .flat_map(|day| day_trials(day).unwrap_or_else(|error| vec![broken(day, error)]))
+        .collect();
+
+    libtest_mimic::run(&arguments, trials).exit();
+}
+
+fn day_trials(day: &'static Day) -> Result<Vec<Trial>, String> {
+    let dir = day.fixtures_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let manifest = Manifest::load(&dir).map_err(|error| error.to_string())?;
+    let files = input_files(&dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+
+    let mut trials = Vec::new();
+    for path in files {
+        let file = path.file_name().unwrap().to_string_lossy().into_owned();
+        let expected = manifest.get(&file).cloned().unwrap_or_default();
+
+        for part in Part::both() {
+            let name = format!("day{}::{}::part{}", day.number, file, part);
+            let expected = expected.get(part).map(str::to_string);
+            let ignored = expected.is_none();
+            let path = path.clone();
+
+            let trial = Trial::test(name, move || {
+                let input = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+                let solved = day.solve(&input, &[part])?;
+                let answer = &solved.answers[0].value;
+                let expected = expected.unwrap_or_default();
+
+                if *answer == expected {
+                    Ok(())
+                } else {
+                    Err(Failed::from(format!(
+                        "expected {}, got {}",
+                        expected, answer
+                    )))
+                }
+            });
+            trials.push(trial.with_ignored_flag(ignored));
+        }
+    }
+
+    Ok(trials)
+}
+
+/// A failing test standing in for a day whose fixtures couldn't be read.
+fn broken(day: &Day, error: String) -> Trial {
+    Trial::test(format!("day{}::fixtures", day.number), move || {
+        Err(Failed::from(error))
+    })
+}
@@ -0,0 +1,372 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Index, IndexMut},
+};
+
+use crate::Location;
+
+/// A position on a [`Grid`], `x` growing to the right and `y` downwards.
+/// Coordinates are signed so stepping off the edge can be checked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point(pub isize, pub isize);
+
+impl Point {
+    /// Steps to the four orthogonal neighbours.
+    pub const ORTHOGONAL: [Point; 4] = [Point(-1, 0), Point(1, 0), Point(0, -1), Point(0, 1)];
+
+    /// Steps to all eight surrounding neighbours.
+    pub const SURROUNDING: [Point; 8] = [
+        Point(-1, -1),
+        Point(0, -1),
+        Point(1, -1),
+        Point(-1, 0),
+        Point(1, 0),
+        Point(-1, 1),
+        Point(0, 1),
+        Point(1, 1),
+    ];
+
+    pub fn x(&self) -> isize {
+        self.0
+    }
+
+    pub fn y(&self) -> isize {
+        self.1
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    InvalidCell(Location),
+    RaggedRow { location: Location, expected: usize },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "the grid is empty"),
+            GridError::InvalidCell(location) => {
+                write!(f, "{}: invalid cell `{}`", location, location.text)
+            }
+            GridError::RaggedRow { location, expected } => write!(
+                f,
+                "{}: expected {} cells like the first row, found `{}`",
+                location, expected, location.text
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// A rectangular grid stored densely, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A grid without rows, to be filled with [`Grid::push_row`].
+    pub fn with_width(width: usize) -> Self {
+        Self {
+            width,
+            height: 0,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Parses one row per non-blank line and one cell per character,
+    /// ignoring whitespace around the rows.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut grid: Option<Grid<T>> = None;
+
+        for (index, line) in input.lines().enumerate() {
+            let row = line.trim();
+            if row.is_empty() {
+                continue;
+            }
+
+            let cells = row
+                .char_indices()
+                .map(|(i, c)| {
+                    cell(c).ok_or_else(|| {
+                        GridError::InvalidCell(Location::new(
+                            index,
+                            line,
+                            &row[i..i + c.len_utf8()],
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<T>, GridError>>()?;
+
+            let grid = grid.get_or_insert_with(|| Grid::with_width(cells.len()));
+            if grid.width != cells.len() {
+                return Err(GridError::RaggedRow {
+                    location: Location::new(index, line, row),
+                    expected: grid.width,
+                });
+            }
+            grid.push_row(cells);
+        }
+
+        grid.ok_or(GridError::Empty)
+    }
+
+    /// Appends a row at the bottom.
+    ///
+    /// # Panics
+    ///
+    /// If the row doesn't have exactly `width` cells.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let before = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(
+            self.cells.len() - before,
+            self.width,
+            "row should have as many cells as the grid is wide"
+        );
+        self.height += 1;
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as isize).contains(&point.x())
+            && (0..self.height as isize).contains(&point.y())
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point(x as isize, y as isize)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Every cell, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The orthogonal neighbours of `point` that are inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours_by(point, &Point::ORTHOGONAL)
+    }
+
+    /// All surrounding neighbours of `point` that are inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours_by(point, &Point::SURROUNDING)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` refuses a size of zero, which a grid without columns has
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Cells from `start` onwards in steps of `step`, until leaving the grid.
+    pub fn walk(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |point| Some(*point + step))
+            .map_while(move |point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The diagonals from the top left and from the top right corner.
+    pub fn diagonals(&self) -> [impl Iterator<Item = &T>; 2] {
+        let right = Point(self.width as isize - 1, 0);
+        [
+            self.diagonal(Point(0, 0), Point(1, 1)),
+            self.diagonal(right, Point(-1, 1)),
+        ]
+    }
+
+    fn diagonal(&self, start: Point, step: Point) -> impl Iterator<Item = &T> {
+        self.walk(start, step).map(|(_, cell)| cell)
+    }
+
+    fn neighbours_by<'a>(
+        &'a self,
+        point: Point,
+        deltas: &'a [Point],
+    ) -> impl Iterator<Item = Point> + 'a {
+        deltas
+            .iter()
+            .map(move |delta| point + *delta)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y() as usize * self.width + point.x() as usize)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                point, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside the {}x{} grid", point, width, height),
+        }
+    }
+}
+
+/// Writes every row on its own line, cells without separators.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows().take(self.height) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, GridError> {
+        Grid::parse(input, |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(digits(" \n"), Err(GridError::Empty));
+        assert_eq!(
+            digits("123\n4x6").unwrap_err().to_string(),
+            "line 2, column 2: invalid cell `x`"
+        );
+        assert_eq!(
+            digits("123\n45").unwrap_err().to_string(),
+            "line 2, column 1: expected 3 cells like the first row, found `45`"
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let grid = Grid::new(2, 3, 0);
+
+        assert!(grid.contains(Point(1, 2)));
+        assert!(!grid.contains(Point(2, 0)));
+        assert!(!grid.contains(Point(0, -1)));
+        assert_eq!(grid.get(Point(0, 3)), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours4(Point(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours4(Point(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_columns_and_diagonals() {
+        let grid = digits("123\n456\n789").unwrap();
+
+        let rows = grid.rows().collect::<Vec<&[u32]>>();
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect())
+            .collect::<Vec<Vec<u32>>>();
+        let [main, anti] = grid.diagonals();
+
+        assert_eq!(rows, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(columns, [[1, 4, 7], [2, 5, 8], [3, 6, 9]]);
+        assert_eq!(main.copied().collect::<Vec<u32>>(), [1, 5, 9]);
+        assert_eq!(anti.copied().collect::<Vec<u32>>(), [3, 5, 7]);
+    }
+
+    #[test]
+    fn test_push_row() {
+        let mut grid = Grid::with_width(2);
+
+        grid.push_row([1, 2]);
+        grid.push_row(vec![3, 4]);
+
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.row(1), [3, 4]);
+    }
+}
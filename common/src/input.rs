@@ -0,0 +1,143 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    io::{self, Read},
+    ops::Deref,
+    path::PathBuf,
+};
+
+use crate::Solution;
+
+/// Where the puzzle input of a run comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The `input/dayN.txt` file compiled into the binary.
+    Bundled,
+    /// Standard input, selected with `-`.
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Interprets a command line argument: no argument means the bundled
+    /// input, `-` means stdin and anything else is a path.
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Bundled,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(self, bundled: &'static str) -> io::Result<Input> {
+        let text = match &self {
+            Source::Bundled => Cow::Borrowed(bundled),
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Cow::Owned(text)
+            }
+            Source::File(path) => Cow::Owned(std::fs::read_to_string(path)?),
+        };
+
+        Ok(Input { source: self, text })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Bundled => write!(f, "bundled input"),
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Puzzle input together with the place it was read from.
+#[derive(Debug)]
+pub struct Input {
+    source: Source,
+    text: Cow<'static, str>,
+}
+
+impl Input {
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    pub fn is_bundled(&self) -> bool {
+        self.source == Source::Bundled
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.text
+    }
+}
+
+/// Reads the input selected by the first command line argument, exiting
+/// with a message if it can't be read.
+pub fn from_args(bundled: &'static str) -> Input {
+    let arg = std::env::args().nth(1);
+    read_or_exit(Source::from_arg(arg.as_deref()), bundled)
+}
+
+/// Reads the input from `source`, exiting with a message if it can't be
+/// read.
+pub fn read_or_exit(source: Source, bundled: &'static str) -> Input {
+    let description = source.to_string();
+
+    match source.read(bundled) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: couldn't read {}: {}", description, error);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Parses `input` for the day `S`, exiting with a diagnostic pointing at the
+/// offending line if it is malformed.
+pub fn parse_or_exit<S: Solution>(input: &Input) -> S::Input {
+    match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("error: couldn't parse {}: {}", input.source(), error);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg(None), Source::Bundled);
+        assert_eq!(Source::from_arg(Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("input/day5.txt")),
+            Source::File(PathBuf::from("input/day5.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read_bundled() {
+        let input = Source::Bundled.read("1\n2\n").unwrap();
+
+        assert!(input.is_bundled());
+        assert_eq!(&*input, "1\n2\n");
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let result = Source::File(PathBuf::from("does/not/exist.txt")).read("");
+
+        assert!(result.is_err());
+    }
+}
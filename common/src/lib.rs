@@ -0,0 +1,12 @@
+pub mod grid;
+pub mod input;
+pub mod lint;
+pub mod parse;
+pub mod rng;
+pub mod solution;
+
+pub use grid::{Grid, Point};
+pub use lint::Lint;
+pub use parse::Location;
+pub use rng::Rng;
+pub use solution::Solution;
@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use crate::Location;
+
+/// A format rule that the input breaks, found without solving it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub location: Location,
+    pub message: String,
+}
+
+impl Lint {
+    pub fn new(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            location,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+/// Checks the rules every input shares: it isn't empty, lines end with `\n`
+/// rather than `\r\n`, nothing trails a line and no blank lines trail the
+/// input.
+pub fn text(input: &str) -> Vec<Lint> {
+    let mut lints = Vec::new();
+    if input.trim().is_empty() {
+        lints.push(Lint::new(Location::line(0, input), "the input is empty"));
+        return lints;
+    }
+
+    for (index, raw) in input.split_inclusive('\n').enumerate() {
+        let line = raw.strip_suffix('\n').unwrap_or(raw);
+        let content = line.strip_suffix('\r').unwrap_or(line);
+        if content.len() < line.len() {
+            lints.push(Lint::new(
+                Location::new(index, line, &line[content.len()..]),
+                "line ends with CRLF instead of LF",
+            ));
+        }
+
+        let trimmed = content.trim_end();
+        if trimmed.len() < content.len() && !trimmed.is_empty() {
+            lints.push(Lint::new(
+                Location::new(index, line, &content[trimmed.len()..]),
+                "trailing whitespace",
+            ));
+        }
+    }
+
+    let blank = input
+        .lines()
+        .rev()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    if blank > 0 {
+        let index = input.lines().count() - blank;
+        lints.push(Lint::new(
+            Location::line(index, ""),
+            format!("{} blank line(s) at the end of the input", blank),
+        ));
+    }
+
+    lints
+}
+
+/// Blank lines between the lines of `input`, for formats that have none.
+/// Blank lines at the end are left to [`text`].
+pub fn blank_lines(input: &str) -> Vec<Lint> {
+    let last = lines(input).last().map_or(0, |(index, _)| index);
+
+    input
+        .lines()
+        .enumerate()
+        .take(last)
+        .filter(|(_, line)| line.trim().is_empty())
+        .map(|(index, line)| Lint::new(Location::line(index, line), "unexpected blank line"))
+        .collect()
+}
+
+/// Checks a grid of one character per cell, like [`crate::Grid::parse`]
+/// reads it: every cell passes `valid`, described by `expected`, and every
+/// row is as wide as the first.
+pub fn grid(input: &str, valid: impl Fn(char) -> bool, expected: &str) -> Vec<Lint> {
+    let mut lints = blank_lines(input);
+    let mut width = None;
+    for (index, line) in lines(input) {
+        let row = line.trim();
+        if let Some((i, c)) = row.char_indices().find(|(_, c)| !valid(*c)) {
+            lints.push(Lint::new(
+                Location::new(index, line, &row[i..i + c.len_utf8()]),
+                format!("expected {}, found `{}`", expected, c),
+            ));
+        }
+
+        let length = row.chars().count();
+        match width {
+            Some(width) if width != length => lints.push(Lint::new(
+                Location::new(index, line, row),
+                format!(
+                    "expected {} cells like the first row, found {}",
+                    width, length
+                ),
+            )),
+            _ => width = Some(length),
+        }
+    }
+
+    lints
+}
+
+/// The non-blank lines of `input` with their 0-based index, for rules that
+/// look at one line at a time.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(input: &str) -> Vec<String> {
+        text(input).iter().map(Lint::to_string).collect()
+    }
+
+    #[test]
+    fn test_text() {
+        assert!(text("1\n2\n").is_empty());
+        assert_eq!(messages(" \n"), ["line 1, column 1: the input is empty"]);
+        assert_eq!(
+            messages("1\r\n2 \n3\t\r\n\n\n"),
+            [
+                "line 1, column 2: line ends with CRLF instead of LF",
+                "line 2, column 2: trailing whitespace",
+                "line 3, column 3: line ends with CRLF instead of LF",
+                "line 3, column 2: trailing whitespace",
+                "line 4, column 1: 2 blank line(s) at the end of the input",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(
+            grid("123\n4x\n789\n", |c| c.is_ascii_digit(), "a digit")
+                .iter()
+                .map(Lint::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "line 2, column 2: expected a digit, found `x`",
+                "line 2, column 1: expected 3 cells like the first row, found 2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        assert!(blank_lines("1\n2\n\n").is_empty());
+        assert_eq!(
+            blank_lines("1\n\n2\n  \n3\n")
+                .iter()
+                .map(Lint::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "line 2, column 1: unexpected blank line",
+                "line 4, column 1: unexpected blank line"
+            ]
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt::Display;
+
+/// The part of the input that failed to parse. `line` and `column` are
+/// 1-based, `column` counting characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl Location {
+    /// Locates `text`, which must be a subslice of `line`, on the line with
+    /// the given 0-based index.
+    pub fn new(index: usize, line: &str, text: &str) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| *offset <= line.len())
+            .expect("text should be a slice of line");
+
+        Self {
+            line: index + 1,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_string(),
+        }
+    }
+
+    /// Locates a whole line.
+    pub fn line(index: usize, line: &str) -> Self {
+        Self::new(index, line, line)
+    }
+
+    /// Moves the location to the line with the given 0-based index, for
+    /// errors produced by parsing a single line on its own.
+    pub fn on_line(self, index: usize) -> Self {
+        Self {
+            line: index + 1,
+            ..self
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_column() {
+        let line = "forward x";
+        let (_, amount) = line.split_once(' ').unwrap();
+
+        let location = Location::new(2, line, amount);
+
+        assert_eq!(location.line, 3);
+        assert_eq!(location.column, 9);
+        assert_eq!(location.text, "x");
+        assert_eq!(location.to_string(), "line 3, column 9");
+    }
+
+    #[test]
+    fn test_location_on_line() {
+        let location = Location::line(0, "up").on_line(4);
+
+        assert_eq!(location.line, 5);
+        assert_eq!(location.column, 1);
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+use crate::Lint;
+
+/// A day's puzzle, split into parsing the input once and solving both parts
+/// from the parsed form.
+pub trait Solution {
+    type Input;
+    type Error: std::error::Error;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// Every break of the day's format rules, where `parse` stops at the
+    /// first one. The rules every input shares are in [`crate::lint::text`].
+    fn lint(_input: &str) -> Vec<Lint> {
+        Vec::new()
+    }
+}
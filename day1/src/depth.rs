@@ -0,0 +1,116 @@
+use std::{
+    cmp::Ordering,
+    ops::{Add, Sub},
+};
+
+/// A number that depths can be counted and windowed as.
+///
+/// Depths are summed as [`Depth::Sum`], a type wide enough that window sums
+/// don't overflow, and ordered by [`Depth::compare`]. Floats are summed as
+/// `f64`, where overflowing sums become infinite instead of wrapping, and
+/// ordered like [`f64::total_cmp`], so every value including NaN has a place.
+pub trait Depth: Copy {
+    type Sum: Copy + Default + Add<Output = Self::Sum> + Sub<Output = Self::Sum>;
+
+    /// Whether sums are exact, so a running sum that adds and subtracts
+    /// depths stays equal to summing them afresh. Float sums round, so they
+    /// are recomputed instead.
+    const EXACT: bool;
+
+    fn widen(self) -> Self::Sum;
+
+    fn compare(a: &Self::Sum, b: &Self::Sum) -> Ordering;
+
+    /// The nearest `f64` to a sum, for means and charts.
+    fn to_f64(sum: Self::Sum) -> f64;
+}
+
+macro_rules! integer_depth {
+    ($($depth:ty => $sum:ty),*) => {
+        $(impl Depth for $depth {
+            type Sum = $sum;
+
+            const EXACT: bool = true;
+
+            fn widen(self) -> $sum {
+                self.into()
+            }
+
+            fn compare(a: &$sum, b: &$sum) -> Ordering {
+                a.cmp(b)
+            }
+
+            fn to_f64(sum: $sum) -> f64 {
+                sum as f64
+            }
+        })*
+    };
+}
+
+integer_depth!(
+    u8 => u64, u16 => u64, u32 => u64, u64 => u128,
+    i8 => i64, i16 => i64, i32 => i64, i64 => i128
+);
+
+impl Depth for f32 {
+    type Sum = f64;
+
+    const EXACT: bool = false;
+
+    fn widen(self) -> f64 {
+        self.into()
+    }
+
+    fn compare(a: &f64, b: &f64) -> Ordering {
+        a.total_cmp(b)
+    }
+
+    fn to_f64(sum: f64) -> f64 {
+        sum
+    }
+}
+
+impl Depth for f64 {
+    type Sum = f64;
+
+    const EXACT: bool = false;
+
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn compare(a: &f64, b: &f64) -> Ordering {
+        a.total_cmp(b)
+    }
+
+    fn to_f64(sum: f64) -> f64 {
+        sum
+    }
+}
+
+/// Whether the depths in `entering` sum to more than those in `leaving`.
+/// Sums start from the first depth rather than zero so that a lone depth
+/// compares exactly like the depth itself.
+pub(crate) fn increased<T: Depth>(
+    leaving: impl IntoIterator<Item = T>,
+    entering: impl IntoIterator<Item = T>,
+) -> bool {
+    let sum = |depths: &mut dyn Iterator<Item = T>| depths.map(T::widen).reduce(|a, b| a + b);
+
+    match (
+        sum(&mut leaving.into_iter()),
+        sum(&mut entering.into_iter()),
+    ) {
+        (Some(leaving), Some(entering)) => greater::<T>(entering, leaving),
+        _ => false,
+    }
+}
+
+pub(crate) fn greater<T: Depth>(a: T::Sum, b: T::Sum) -> bool {
+    T::compare(&a, &b) == Ordering::Greater
+}
+
+/// Orders two depths the way their sums are ordered.
+pub(crate) fn compare<T: Depth>(a: T, b: T) -> Ordering {
+    T::compare(&a.widen(), &b.widen())
+}
@@ -0,0 +1,26 @@
+use common::Rng;
+
+/// Generates `size` sonar depths as a random walk that drifts deeper, like
+/// the sea floor in the puzzle.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut depth = rng.range(100..=200);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        input += &format!("{}\n", depth);
+        depth = (depth + rng.range(-15..=20)).max(0);
+    }
+
+    input
+}
+
+#[test]
+fn test_generate() {
+    use common::Solution;
+
+    let input = generate(3, 500);
+
+    assert_eq!(input, generate(3, 500));
+    assert_eq!(crate::Day1::parse(&input).unwrap().len(), 500);
+}
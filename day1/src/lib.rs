@@ -0,0 +1,259 @@
+pub mod depth;
+pub mod generator;
+pub mod plot;
+pub mod profile;
+pub mod stream;
+
+pub use depth::Depth;
+
+use std::{fmt::Display, str::FromStr};
+
+use common::{lint, Lint, Location, Solution};
+
+pub const INPUT: &str = include_str!("../input/day1.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidNumber(Location),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidNumber(location) => {
+                write!(f, "{}: invalid number `{}`", location, location.text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Sonar Sweep: counts how often the sea floor gets deeper.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<u32>;
+    type Error = ParseError;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_lines::<u32>(input)
+    }
+
+    fn part1(numbers: &Self::Input) -> u32 {
+        count_increases(numbers)
+    }
+
+    fn part2(numbers: &Self::Input) -> u32 {
+        count_window_increases(numbers, 3, 1)
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut lints = lint::blank_lines(input);
+        for (index, line) in lint::lines(input) {
+            let depth = line.trim();
+            if depth.parse::<u32>().is_err() {
+                lints.push(Lint::new(
+                    Location::new(index, line, depth),
+                    format!("expected a depth, found `{}`", depth),
+                ));
+            }
+        }
+
+        lints
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    Ok(Day1::part1(&Day1::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    Ok(Day1::part2(&Day1::parse(input)?))
+}
+
+/// Counts how often a number is larger than the one before it.
+pub fn count_increases<T: Depth>(numbers: &[T]) -> u32 {
+    increases(numbers).count() as u32
+}
+
+/// The indices of the numbers [`count_increases`] counts.
+pub fn increases<T: Depth>(numbers: &[T]) -> impl Iterator<Item = usize> + '_ {
+    numbers
+        .windows(2)
+        .enumerate()
+        .filter(|(_, window)| depth::increased([window[0]], [window[1]]))
+        .map(|(i, _)| i + 1)
+}
+
+/// Counts how often the sum of `window` consecutive numbers is larger than
+/// the sum of the window starting `lag` numbers earlier.
+///
+/// The two windows share all but `window.min(lag)` of their numbers, so only
+/// the numbers entering and leaving are compared, each kept as a running sum
+/// that moves by one number per step. Sums are widened so large depths can't
+/// overflow. Float sums round, so for floats the numbers entering and leaving
+/// are summed afresh each step instead, keeping sums of the same numbers
+/// equal.
+pub fn count_window_increases<T: Depth>(numbers: &[T], window: usize, lag: usize) -> u32 {
+    let changed = window.min(lag);
+    let entering_start = window.max(lag);
+    // Comparing a window with itself, or empty windows, never increases, and
+    // nor do windows too long to fit in the input
+    let Some(span) = window.checked_add(lag).filter(|_| changed > 0) else {
+        return 0;
+    };
+    let comparisons = (numbers.len() + 1).saturating_sub(span);
+    if comparisons == 0 {
+        return 0;
+    }
+
+    if !T::EXACT {
+        return (0..comparisons)
+            .filter(|&i| {
+                let entering = i + entering_start;
+                depth::increased(
+                    numbers[i..i + changed].iter().copied(),
+                    numbers[entering..entering + changed].iter().copied(),
+                )
+            })
+            .count() as u32;
+    }
+
+    let sum = |numbers: &[T]| {
+        numbers
+            .iter()
+            .fold(T::Sum::default(), |sum, &n| sum + n.widen())
+    };
+    let mut leaving = sum(&numbers[..changed]);
+    let mut entering = sum(&numbers[entering_start..entering_start + changed]);
+    let mut count = depth::greater::<T>(entering, leaving) as u32;
+    for i in 1..comparisons {
+        leaving = leaving + numbers[i + changed - 1].widen() - numbers[i - 1].widen();
+        let (added, removed) = (entering_start + i + changed - 1, entering_start + i - 1);
+        entering = entering + numbers[added].widen() - numbers[removed].widen();
+        count += depth::greater::<T>(entering, leaving) as u32;
+    }
+
+    count
+}
+
+/// Parses one number per line.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let number = line.trim();
+            number
+                .parse::<T>()
+                .map_err(|_| ParseError::InvalidNumber(Location::new(index, line, number)))
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_error() {
+    let result = part1("199\n200\n 2o8\n");
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "line 3, column 2: invalid number `2o8`"
+    )
+}
+
+#[test]
+fn test_large_window_sums() {
+    let result = part2("4294967295\n4294967295\n4294967295\n4294967295\n4294967295\n0\n");
+
+    assert_eq!(result.unwrap(), 0);
+}
+
+#[test]
+fn test_count_window_increases() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    assert_eq!(count_window_increases(&depths, 1, 1), 7);
+    assert_eq!(count_window_increases(&depths, 3, 1), 5);
+    // Pairs sum to 399, 408, 418, 410, 407, 447, 509, 529, 523 and only
+    // 407 isn't larger than the sum three before it
+    assert_eq!(count_window_increases(&depths, 2, 3), 5);
+    assert_eq!(count_window_increases(&depths, 5, 7), 0);
+    assert_eq!(count_window_increases(&depths, 0, 1), 0);
+    assert_eq!(count_window_increases(&depths, 3, 0), 0);
+    assert_eq!(count_window_increases(&depths, usize::MAX, 1), 0);
+    assert_eq!(count_window_increases(&depths, 1, usize::MAX), 0);
+    assert_eq!(count_window_increases(&depths, usize::MAX, usize::MAX), 0);
+}
+
+#[test]
+fn test_signed_depths() {
+    assert_eq!(count_increases(&[-5, -3, -3, -10, 0]), 2);
+    assert_eq!(count_window_increases(&[-5, -3, -3, -10, 0], 2, 1), 2);
+    // Sums of three i8s are out of range for i8
+    assert_eq!(count_window_increases(&[i8::MIN, 127, 127, 127], 3, 1), 1);
+    assert_eq!(count_window_increases(&[127, 127, 127, i8::MIN], 3, 1), 0);
+    assert_eq!(count_window_increases(&[i16::MIN, i16::MAX, 0], 1, 2), 1);
+}
+
+#[test]
+fn test_wide_depths() {
+    assert_eq!(
+        count_window_increases(&[0, u64::MAX, u64::MAX, u64::MAX], 3, 1),
+        1
+    );
+    assert_eq!(count_window_increases(&[u64::MAX; 6], 3, 1), 0);
+    assert_eq!(
+        count_window_increases(&[i64::MAX, i64::MAX, i64::MIN], 2, 1),
+        0
+    );
+    assert_eq!(
+        count_window_increases(&[i64::MIN, i64::MAX, i64::MAX], 2, 1),
+        1
+    );
+    assert_eq!(count_window_increases(&[255u8, 255, 254, 255], 2, 2), 0);
+    assert_eq!(count_increases(&[1u16, 65535]), 1);
+}
+
+#[test]
+fn test_float_depths() {
+    let depths = parse_lines::<f64>("0.1\n0.2\n0.3\n").unwrap();
+    assert_eq!(count_increases(&depths), 2);
+    assert_eq!(count_window_increases(&depths, 2, 1), 1);
+
+    // Windows of the same numbers in another order never increase, which
+    // running sums of floats can't promise
+    let depths = [0.1, 0.7, 0.2].repeat(500);
+    assert_eq!(count_window_increases(&depths, 3, 1), 0);
+
+    // Ordered like `total_cmp`
+    assert_eq!(count_increases(&[-0.0, 0.0]), 1);
+    assert_eq!(count_increases(&[1.0, f64::NAN, 1.0]), 1);
+    assert_eq!(count_increases(&[f64::NEG_INFINITY, f64::INFINITY]), 1);
+
+    // f32 sums are f64 and don't overflow, f64 sums become infinite
+    assert_eq!(
+        count_window_increases(&[1.5f32, 2.5, f32::MAX, f32::MAX], 2, 1),
+        2
+    );
+    assert_eq!(count_window_increases(&[f64::MAX, f64::MAX, 1.0], 2, 1), 0);
+    assert_eq!(count_window_increases(&[1.0, f64::MAX, f64::MAX], 2, 1), 1);
+}
+
+#[test]
+fn test_lint() {
+    let lints = Day1::lint("199\n\n-3\n208\nabc\n");
+
+    assert_eq!(
+        lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+        [
+            "line 2, column 1: unexpected blank line",
+            "line 3, column 1: expected a depth, found `-3`",
+            "line 5, column 1: expected a depth, found `abc`",
+        ]
+    );
+}
@@ -1,55 +1,100 @@
-use std::str::FromStr;
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    str::FromStr,
+};
 
-fn part1(input: &str) -> u32 {
-    let numbers = parse_lines::<u32>(input);
+use common::{input::Source, Solution};
+use day1::{
+    plot,
+    profile::Profile,
+    stream::{self, Sweep},
+    Day1, Depth,
+};
 
-    count_increases(numbers)
-}
+fn main() {
+    let args = std::env::args().collect::<Vec<_>>();
+    // The extra modes read `[--float] [PATH|-]`, with whole depths by default
+    let mut rest = args.iter().skip(2).map(String::as_str).peekable();
+    let float = rest.next_if_eq(&"--float").is_some();
+    let source = Source::from_arg(rest.next());
+    match (args.get(1).map(String::as_str), float) {
+        (Some("--stream"), false) => return stream::<i64>(source),
+        (Some("--stream"), true) => return stream::<f64>(source),
+        (Some("--profile"), false) => return profile::<i64>(source),
+        (Some("--profile"), true) => return profile::<f64>(source),
+        (Some("--plot"), false) => return plot::<i64>(source, false),
+        (Some("--plot"), true) => return plot::<f64>(source, false),
+        (Some("--svg"), false) => return plot::<i64>(source, true),
+        (Some("--svg"), true) => return plot::<f64>(source, true),
+        _ => {}
+    }
 
-fn part2(input: &str) -> u32 {
-    let numbers = parse_lines::<u32>(input);
-    let windows = numbers.windows(3).map(|x| x.iter().sum()).collect();
+    let input = common::input::from_args(day1::INPUT);
+    let numbers = common::input::parse_or_exit::<Day1>(&input);
 
-    count_increases(windows)
+    let result = Day1::part1(&numbers);
+    println!("Result for part 1 was: {}", result);
+
+    let result = Day1::part2(&numbers);
+    println!("Result for part 2 was: {}", result);
 }
 
-fn count_increases(vec: Vec<u32>) -> u32 {
-    vec.windows(2).fold(
-        0,
-        |sum, window| if window[0] < window[1] { sum + 1 } else { sum },
-    )
+/// Reads and parses the depths in `source`, exiting with a message if it
+/// can't.
+fn depths<T: FromStr>(source: Source) -> Vec<T> {
+    let input = common::input::read_or_exit(source, day1::INPUT);
+    match day1::parse_lines(&input) {
+        Ok(depths) => depths,
+        Err(error) => {
+            eprintln!("error: couldn't parse {}: {}", input.source(), error);
+            std::process::exit(1);
+        }
+    }
 }
 
-fn parse_lines<T>(input: &str) -> Vec<T>
+/// Prints statistics of the depths in `source`.
+fn profile<T>(source: Source)
 where
-    T: FromStr,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
+    T: Depth + FromStr + Display,
+    T::Sum: Display,
 {
-    input.lines().map(|x| x.parse::<T>().unwrap()).collect()
+    print!("{}", Profile::new(&depths::<T>(source)));
 }
 
-fn main() {
-    let input = include_str!("../input/day1.txt");
-    let result = part1(input);
-    println!("Result for part 1 was: {}", result);
-    assert_eq!(result, 1288);
+/// Prints a chart of the depths in `source` and their three-measurement
+/// windows, as SVG or as text.
+fn plot<T: Depth + FromStr + Display>(source: Source, svg: bool) {
+    let depths = depths::<T>(source);
 
-    let input = include_str!("../input/day1.txt");
-    let result = part2(input);
-    println!("Result for part 2 was: {}", result);
-    assert_eq!(result, 1311);
+    if svg {
+        print!("{}", plot::svg(&depths, 3));
+    } else {
+        print!("{}", plot::ascii(&depths, 3, 72, 20));
+    }
 }
 
-#[test]
-fn test_part_1() {
-    let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
-    let result = part1(input);
-    assert_eq!(result, 7)
-}
+/// Solves both parts in one pass over `source` without loading it.
+fn stream<T: Depth + FromStr>(source: Source) {
+    let reader: io::Result<Box<dyn BufRead>> = match &source {
+        Source::Bundled => Ok(Box::new(day1::INPUT.as_bytes())),
+        Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        Source::File(path) => File::open(path).map(|file| Box::new(BufReader::new(file)) as _),
+    };
+
+    let (mut part1, mut part2) = (Sweep::<T>::new(1, 1), Sweep::new(3, 1));
+    let result = reader.map_err(Into::into).and_then(|reader| {
+        stream::read_depths(reader, |depth| {
+            part1.push(depth);
+            part2.push(depth);
+        })
+    });
+    if let Err(error) = result {
+        eprintln!("error: couldn't read {}: {}", source, error);
+        std::process::exit(1);
+    }
 
-#[test]
-fn test_part_2() {
-    let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
-    let result = part2(input);
-    assert_eq!(result, 5)
+    println!("Result for part 1 was: {}", part1.increases());
+    println!("Result for part 2 was: {}", part2.increases());
 }
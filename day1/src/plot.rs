@@ -0,0 +1,311 @@
+//! Charts of a depth series, drawn with depth growing downwards like the sea
+//! floor, next to the same series averaged over a sliding window.
+
+use std::fmt::Display;
+
+use crate::{depth, increases, Depth};
+
+const SVG_WIDTH: f64 = 800.0;
+const SVG_HEIGHT: f64 = 300.0;
+const SVG_MARGIN: f64 = 40.0;
+
+/// The mean of every `window` consecutive depths, one per window start.
+pub fn smooth<T: Depth>(depths: &[T], window: usize) -> Vec<f64> {
+    if window == 0 {
+        return Vec::new();
+    }
+
+    depths
+        .windows(window)
+        .map(|window| {
+            let sum = window
+                .iter()
+                .fold(T::Sum::default(), |sum, &depth| sum + depth.widen());
+            T::to_f64(sum) / window.len() as f64
+        })
+        .collect()
+}
+
+/// Draws the depths, `+` where they increase and `.` elsewhere, and their
+/// `window` smoothed series as `-` on top, in at most `width` columns of
+/// `height` rows. When there are more depths than columns, a column shows
+/// every row its depths fall in, `+` where most of them increased, and the
+/// mean of its smoothed values, which covers a depth unless it's the
+/// column's only one.
+pub fn ascii<T: Depth + Display>(
+    depths: &[T],
+    window: usize,
+    width: usize,
+    height: usize,
+) -> String {
+    if depths.is_empty() || width == 0 || height == 0 {
+        return String::new();
+    }
+
+    let columns = width.min(depths.len());
+    let column = |index: usize| index * columns / depths.len();
+    let scale = Scale::new(depths);
+    // NaN and infinite depths can't be placed, so they end up on an edge
+    let row = |depth: f64| {
+        let row = (scale.fraction(depth) * (height - 1) as f64).round();
+        row.clamp(0.0, (height - 1) as f64) as usize
+    };
+
+    // How many depths fall in each cell and how many of them increased
+    let mut counts = vec![vec![(0, 0); columns]; height];
+    let mut increased = increases(depths).peekable();
+    for (i, &depth) in depths.iter().enumerate() {
+        let (count, up) = &mut counts[row(value(depth))][column(i)];
+        *count += 1;
+        if increased.next_if_eq(&i).is_some() {
+            *up += 1;
+        }
+    }
+    let mut grid = counts
+        .iter()
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|&(count, up)| match count {
+                    0 => ' ',
+                    _ if 2 * up > count => '+',
+                    _ => '.',
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Smoothed values sit at the middle of their window. They're drawn over
+    // the depths unless that would hide the only depth left in the column
+    for (column, values) in buckets(
+        smooth(depths, window)
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| (column(i + (window - 1) / 2), value)),
+    ) {
+        let row = row(mean(&values));
+        let depth_rows = counts.iter().filter(|cells| cells[column].0 > 0).count();
+        if counts[row][column].0 == 0 || depth_rows > 1 {
+            grid[row][column] = '-';
+        }
+    }
+
+    let Some((min, max)) = extremes(depths) else {
+        return String::new();
+    };
+    let (top, bottom) = (min.to_string(), max.to_string());
+    let label = top.len().max(bottom.len());
+    let mut chart = String::new();
+    for (i, cells) in grid.iter().enumerate() {
+        let depth = match i {
+            0 => &top,
+            i if i == height - 1 => &bottom,
+            _ => "",
+        };
+        let cells = cells.iter().collect::<String>();
+        chart += &format!("{:>label$} |{}\n", depth, cells.trim_end());
+    }
+    chart += &format!("{:>label$} +{}\n", "", "-".repeat(columns));
+    chart += &format!("{:>label$}  0", "");
+    if depths.len() > 1 {
+        let last = (depths.len() - 1).to_string();
+        let width = (columns - 1).max(last.len() + 1);
+        chart += &format!("{:>width$}", last);
+    }
+    chart += "\n";
+
+    chart
+}
+
+/// Draws the depths, their `window` smoothed series and a dot on every
+/// depth that increased as a standalone SVG document.
+pub fn svg<T: Depth + Display>(depths: &[T], window: usize) -> String {
+    let scale = Scale::new(depths);
+    let x = |index: f64| {
+        let last = depths.len().saturating_sub(1).max(1) as f64;
+        SVG_MARGIN + index / last * (SVG_WIDTH - 2.0 * SVG_MARGIN)
+    };
+    let y = |depth: f64| SVG_MARGIN + scale.fraction(depth) * (SVG_HEIGHT - 2.0 * SVG_MARGIN);
+    let points = |points: &mut dyn Iterator<Item = (f64, f64)>| {
+        points
+            .map(|(index, depth)| format!("{:.1},{:.1}", x(index), y(depth)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+        w = SVG_WIDTH,
+        h = SVG_HEIGHT
+    );
+    svg += "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n";
+    svg += &format!(
+        "<text x=\"{:.1}\" y=\"{:.1}\" font-family=\"sans-serif\" font-size=\"12\">{} depths, {} increases{}</text>\n",
+        SVG_MARGIN,
+        SVG_MARGIN / 2.0,
+        depths.len(),
+        increases(depths).count(),
+        extremes(depths).map_or(String::new(), |(min, max)| format!(", min {} max {}", min, max))
+    );
+    svg += &format!(
+        "<polyline fill=\"none\" stroke=\"steelblue\" stroke-width=\"1\" points=\"{}\"/>\n",
+        points(
+            &mut depths
+                .iter()
+                .enumerate()
+                .map(|(i, &depth)| (i as f64, value(depth)))
+        )
+    );
+    svg += &format!(
+        "<polyline fill=\"none\" stroke=\"darkorange\" stroke-width=\"2\" points=\"{}\"/>\n",
+        points(
+            &mut smooth(depths, window)
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| (i as f64 + (window as f64 - 1.0) / 2.0, value))
+        )
+    );
+    for i in increases(depths) {
+        svg += &format!(
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"1.5\" fill=\"seagreen\"/>\n",
+            x(i as f64),
+            y(value(depths[i]))
+        );
+    }
+    svg += "</svg>\n";
+
+    svg
+}
+
+/// The shallowest and deepest depth, ordered like [`crate::count_increases`].
+fn extremes<T: Depth>(depths: &[T]) -> Option<(T, T)> {
+    let min = depths
+        .iter()
+        .copied()
+        .min_by(|&a, &b| depth::compare(a, b))?;
+    let max = depths
+        .iter()
+        .copied()
+        .max_by(|&a, &b| depth::compare(a, b))?;
+
+    Some((min, max))
+}
+
+fn value<T: Depth>(depth: T) -> f64 {
+    T::to_f64(depth.widen())
+}
+
+/// Maps depths between the shallowest and deepest one onto `0.0..=1.0`.
+struct Scale {
+    min: f64,
+    max: f64,
+}
+
+impl Scale {
+    fn new<T: Depth>(depths: &[T]) -> Self {
+        let (min, max) = extremes(depths).map_or((0.0, 0.0), |(min, max)| (value(min), value(max)));
+
+        Self { min, max }
+    }
+
+    fn fraction(&self, depth: f64) -> f64 {
+        if self.max == self.min {
+            0.0
+        } else {
+            (depth - self.min) / (self.max - self.min)
+        }
+    }
+}
+
+/// Groups values by column, in column order.
+fn buckets(values: impl Iterator<Item = (usize, f64)>) -> Vec<(usize, Vec<f64>)> {
+    let mut buckets: Vec<(usize, Vec<f64>)> = Vec::new();
+    for (column, value) in values {
+        match buckets.last_mut() {
+            Some((last, values)) if *last == column => values.push(value),
+            _ => buckets.push((column, vec![value])),
+        }
+    }
+
+    buckets
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+#[test]
+fn test_smooth() {
+    assert_eq!(smooth(&[1, 2, 3, 6], 3), [2.0, 11.0 / 3.0]);
+    assert!(smooth(&[1, 2], 3).is_empty());
+    assert!(smooth(&[1, 2], 0).is_empty());
+}
+
+#[test]
+fn test_ascii() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    assert_eq!(
+        ascii(&depths, 3, 80, 5),
+        [
+            "199 |.+--.+",
+            "    |  ++ -",
+            "    |      +",
+            "    |       -.",
+            "269 |       +-+",
+            "    +----------",
+            "     0        9",
+            "",
+        ]
+        .join("\n")
+    );
+    assert_eq!(ascii(&depths, 3, 5, 2).lines().count(), 4);
+    assert_eq!(ascii::<u32>(&[], 3, 80, 5), "");
+}
+
+#[test]
+fn test_ascii_buckets() {
+    use common::Solution;
+
+    let depths = crate::Day1::parse(crate::INPUT).unwrap();
+
+    let chart = ascii(&depths, 3, 72, 20);
+
+    assert!(chart.contains('-'));
+    assert!(chart.contains('+'));
+}
+
+#[test]
+fn test_numeric_types() {
+    assert_eq!(
+        smooth(&[-3i64, 5, i64::MAX, i64::MAX], 2),
+        [1.0, 4611686018427387906.0, i64::MAX as f64]
+    );
+    assert_eq!(
+        ascii(&[-1.5, 2.0, -0.5], 1, 80, 3),
+        [
+            "-1.5 |.",
+            "     |  .",
+            "   2 | +",
+            "     +---",
+            "      0 2",
+            ""
+        ]
+        .join("\n")
+    );
+    // Depths that can't be placed don't break the chart
+    let chart = ascii(&[1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY], 3, 80, 5);
+    assert_eq!(chart.lines().count(), 7);
+    assert_eq!(svg(&[-2i8, 7, 3], 2).matches("<circle").count(), 1);
+}
+
+#[test]
+fn test_svg() {
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    let svg = svg(&depths, 3);
+
+    assert!(svg.starts_with("<svg"));
+    assert_eq!(svg.matches("<circle").count(), 7);
+    assert_eq!(svg.matches("<polyline").count(), 2);
+}
@@ -0,0 +1,240 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use crate::{count_increases, depth, Depth};
+
+/// Consecutive depths from `start` to `end`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Run {
+    pub fn length(&self) -> usize {
+        self.end - self.start + 1
+    }
+}
+
+/// The change from the depth before `index` to the depth at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jump<T> {
+    pub index: usize,
+    pub from: T,
+    pub to: T,
+}
+
+impl<T: Depth> Jump<T> {
+    pub fn is_rise(&self) -> bool {
+        depth::compare(self.to, self.from) == Ordering::Greater
+    }
+
+    /// How far the depth moved, either way.
+    pub fn size(&self) -> T::Sum {
+        let (from, to) = (self.from.widen(), self.to.widen());
+        if self.is_rise() {
+            to - from
+        } else {
+            from - to
+        }
+    }
+}
+
+/// A depth and the index it first occurs at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Extreme<T> {
+    pub index: usize,
+    pub depth: T,
+}
+
+/// Statistics of a depth series beyond how often it gets deeper, with depths
+/// ordered like [`count_increases`] orders them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile<T> {
+    pub depths: usize,
+    pub increases: u32,
+    pub decreases: u32,
+    pub plateaus: u32,
+    /// The first of the longest strictly increasing runs.
+    pub longest_run: Option<Run>,
+    /// The first of the largest changes either way between neighbours.
+    pub largest_jump: Option<Jump<T>>,
+    pub min: Option<Extreme<T>>,
+    pub max: Option<Extreme<T>>,
+    pub mean: Option<f64>,
+}
+
+impl<T: Depth> Profile<T> {
+    pub fn new(depths: &[T]) -> Self {
+        let increases = count_increases(depths);
+        let mut decreases = 0;
+        let mut plateaus = 0;
+        let mut longest_run = (!depths.is_empty()).then_some(Run { start: 0, end: 0 });
+        let mut run = Run { start: 0, end: 0 };
+        let mut largest_jump: Option<Jump<T>> = None;
+
+        for (i, pair) in depths.windows(2).enumerate() {
+            let index = i + 1;
+            match depth::compare(pair[0], pair[1]) {
+                Ordering::Less => run.end = index,
+                Ordering::Greater => {
+                    decreases += 1;
+                    run = Run {
+                        start: index,
+                        end: index,
+                    };
+                }
+                Ordering::Equal => {
+                    plateaus += 1;
+                    run = Run {
+                        start: index,
+                        end: index,
+                    };
+                }
+            }
+            if longest_run.is_some_and(|longest| run.length() > longest.length()) {
+                longest_run = Some(run);
+            }
+
+            let jump = Jump {
+                index,
+                from: pair[0],
+                to: pair[1],
+            };
+            if largest_jump.is_none_or(|largest| depth::greater::<T>(jump.size(), largest.size())) {
+                largest_jump = Some(jump);
+            }
+        }
+
+        // Only strictly smaller or larger depths replace the first one found
+        let mut min: Option<Extreme<T>> = None;
+        let mut max: Option<Extreme<T>> = None;
+        for (index, &depth) in depths.iter().enumerate() {
+            if min.is_none_or(|min| depth::compare(depth, min.depth) == Ordering::Less) {
+                min = Some(Extreme { index, depth });
+            }
+            if max.is_none_or(|max| depth::compare(depth, max.depth) == Ordering::Greater) {
+                max = Some(Extreme { index, depth });
+            }
+        }
+        let sum = depths
+            .iter()
+            .fold(T::Sum::default(), |sum, &depth| sum + depth.widen());
+
+        Self {
+            depths: depths.len(),
+            increases,
+            decreases,
+            plateaus,
+            longest_run,
+            largest_jump,
+            min,
+            max,
+            mean: (!depths.is_empty()).then(|| T::to_f64(sum) / depths.len() as f64),
+        }
+    }
+}
+
+impl<T> Display for Profile<T>
+where
+    T: Depth + Display,
+    T::Sum: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Depths:        {}", self.depths)?;
+        writeln!(f, "Increases:     {}", self.increases)?;
+        writeln!(f, "Decreases:     {}", self.decreases)?;
+        writeln!(f, "Plateaus:      {}", self.plateaus)?;
+        if let Some(run) = self.longest_run {
+            writeln!(
+                f,
+                "Longest rise:  {} depths, indices {} to {}",
+                run.length(),
+                run.start,
+                run.end
+            )?;
+        }
+        if let Some(jump) = self.largest_jump {
+            writeln!(
+                f,
+                "Largest jump:  {}{} ({} to {}) at index {}",
+                if jump.is_rise() { '+' } else { '-' },
+                jump.size(),
+                jump.from,
+                jump.to,
+                jump.index
+            )?;
+        }
+        if let Some(min) = self.min {
+            writeln!(f, "Min depth:     {} at index {}", min.depth, min.index)?;
+        }
+        if let Some(max) = self.max {
+            writeln!(f, "Max depth:     {} at index {}", max.depth, max.index)?;
+        }
+        if let Some(mean) = self.mean {
+            writeln!(f, "Mean depth:    {:.2}", mean)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_profile() {
+    let profile = Profile::new(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+
+    assert_eq!(profile.increases, 7);
+    assert_eq!(profile.decreases, 2);
+    assert_eq!(profile.plateaus, 0);
+    assert_eq!(profile.longest_run, Some(Run { start: 0, end: 3 }));
+    assert_eq!(profile.largest_jump.map(|jump| jump.index), Some(6));
+    assert_eq!(
+        profile.min,
+        Some(Extreme {
+            index: 0,
+            depth: 199
+        })
+    );
+    assert_eq!(
+        profile.max,
+        Some(Extreme {
+            index: 7,
+            depth: 269
+        })
+    );
+    assert_eq!(profile.mean, Some(225.6));
+
+    let profile = Profile::new(&[5, 5, 3, 5]);
+    assert_eq!(profile.plateaus, 1);
+    assert_eq!(profile.min.map(|min| min.index), Some(2));
+    assert_eq!(profile.max.map(|max| max.index), Some(0));
+    assert_eq!(Profile::<u32>::new(&[]).longest_run, None);
+}
+
+#[test]
+fn test_profile_types() {
+    let profile = Profile::new(&[-3, -10, 4, i64::MIN]);
+    assert_eq!(profile.largest_jump.map(|jump| jump.index), Some(3));
+    assert_eq!(
+        profile.largest_jump.map(|jump| jump.size()),
+        Some(i64::MAX as i128 + 5)
+    );
+    assert_eq!(profile.min.map(|min| min.index), Some(3));
+    assert_eq!(profile.max.map(|max| max.depth), Some(4));
+
+    let profile = Profile::new(&[u64::MAX, u64::MAX, 0]);
+    assert_eq!(profile.mean, Some(u64::MAX as f64 * 2.0 / 3.0));
+    assert_eq!(
+        profile
+            .largest_jump
+            .map(|jump| (jump.is_rise(), jump.size())),
+        Some((false, u64::MAX as u128))
+    );
+
+    let profile = Profile::new(&[0.5, 0.25, 0.25, 1.5]);
+    assert_eq!(profile.plateaus, 1);
+    assert_eq!(profile.mean, Some(0.625));
+    assert_eq!(
+        profile.to_string().lines().nth(5),
+        Some("Largest jump:  +1.25 (0.25 to 1.5) at index 3")
+    );
+}
@@ -0,0 +1,204 @@
+use std::{
+    fmt::Display,
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+use common::Location;
+
+use crate::{depth, Depth, ParseError};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(error) => error.fmt(f),
+            StreamError::Parse(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+/// Counts window increases like [`crate::count_window_increases`] one depth
+/// at a time, remembering only the last `window + lag` depths. The buffer
+/// grows as depths arrive, so a window longer than the input costs nothing.
+#[derive(Debug, Clone)]
+pub struct Sweep<T: Depth> {
+    changed: usize,
+    /// `None` when no window can ever increase.
+    span: Option<usize>,
+    recent: Vec<T>,
+    seen: usize,
+    /// Running sums of the depths entering and leaving, for exact types.
+    entering: T::Sum,
+    leaving: T::Sum,
+    increases: u64,
+}
+
+impl<T: Depth> Sweep<T> {
+    pub fn new(window: usize, lag: usize) -> Self {
+        let changed = window.min(lag);
+        Self {
+            changed,
+            // Comparing a window with itself never increases, and windows
+            // too long for `usize` never fit
+            span: window.checked_add(lag).filter(|_| changed > 0),
+            recent: Vec::new(),
+            seen: 0,
+            entering: T::Sum::default(),
+            leaving: T::Sum::default(),
+            increases: 0,
+        }
+    }
+
+    pub fn push(&mut self, depth: T) {
+        let (n, changed) = (self.seen, self.changed);
+        self.seen += 1;
+        let Some(span) = self.span else {
+            return;
+        };
+
+        // The depth at index `i` lives in slot `i % span`. `entering` sums
+        // the last `changed` depths and `leaving` the `changed` depths
+        // starting `span - 1` before this one
+        if T::EXACT {
+            self.entering = self.entering + depth.widen();
+            if n >= changed {
+                self.entering = self.entering - self.recent[(n - changed) % span].widen();
+            }
+            if n < changed {
+                self.leaving = self.leaving + depth.widen();
+            } else if n >= span {
+                // The slot about to be overwritten holds the depth `span` back
+                self.leaving = self.leaving + self.recent[(n - span + changed) % span].widen()
+                    - self.recent[n % span].widen();
+            }
+        }
+        if self.recent.len() < span {
+            self.recent.push(depth);
+        } else {
+            self.recent[n % span] = depth;
+        }
+
+        if n + 1 >= span {
+            let increased = if T::EXACT {
+                depth::greater::<T>(self.entering, self.leaving)
+            } else {
+                let start = n + 1 - span;
+                let at = |i: usize| self.recent[i % span];
+                depth::increased(
+                    (start..start + changed).map(at),
+                    (n + 1 - changed..=n).map(at),
+                )
+            };
+            self.increases += increased as u64;
+        }
+    }
+
+    pub fn increases(&self) -> u64 {
+        self.increases
+    }
+}
+
+/// Reads one depth per line from `reader`, calling `each` with every depth
+/// without keeping any of them.
+pub fn read_depths<T: FromStr, R: BufRead>(
+    mut reader: R,
+    mut each: impl FnMut(T),
+) -> Result<(), StreamError> {
+    let mut buffer = String::new();
+    for index in 0.. {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+
+        let line = buffer.trim_end_matches(['\n', '\r']);
+        let depth = line.trim();
+        let depth = depth
+            .parse()
+            .map_err(|_| ParseError::InvalidNumber(Location::new(index, line, depth)))?;
+        each(depth);
+    }
+
+    Ok(())
+}
+
+/// Counts window increases in the depths read from `reader` in constant
+/// memory.
+pub fn count_window_increases<T: Depth + FromStr, R: BufRead>(
+    reader: R,
+    window: usize,
+    lag: usize,
+) -> Result<u64, StreamError> {
+    let mut sweep = Sweep::<T>::new(window, lag);
+    read_depths(reader, |depth| sweep.push(depth))?;
+
+    Ok(sweep.increases())
+}
+
+#[test]
+fn test_count_window_increases() {
+    let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    assert_eq!(
+        count_window_increases::<u32, _>(input.as_bytes(), 1, 1).unwrap(),
+        7
+    );
+    assert_eq!(
+        count_window_increases::<u32, _>(input.as_bytes(), 3, 1).unwrap(),
+        5
+    );
+    assert_eq!(
+        count_window_increases::<u32, _>(input.as_bytes(), 2, 3).unwrap(),
+        5
+    );
+    assert_eq!(
+        count_window_increases::<u32, _>(input.as_bytes(), 3, 0).unwrap(),
+        0
+    );
+    assert_eq!(
+        count_window_increases::<u32, _>(input.as_bytes(), usize::MAX, 1).unwrap(),
+        0
+    );
+    // Would abort if the buffer were allocated up front
+    assert_eq!(
+        count_window_increases::<u32, _>(input.as_bytes(), 1 << 40, 1 << 40).unwrap(),
+        0
+    );
+    assert_eq!(
+        count_window_increases::<f64, _>("0.5\n-1.5\n2\n2e1\n".as_bytes(), 1, 1).unwrap(),
+        2
+    );
+    assert_eq!(
+        count_window_increases::<u32, _>("199\r\n200\r\n 2o8\r\n".as_bytes(), 1, 1)
+            .unwrap_err()
+            .to_string(),
+        "line 3, column 2: invalid number `2o8`"
+    );
+
+    // Logs can hold more increases than a u32 counts
+    let mut sweep = Sweep::<u8>::new(1, 1);
+    sweep.increases = u32::MAX as u64;
+    sweep.push(1);
+    sweep.push(2);
+    assert_eq!(sweep.increases(), u32::MAX as u64 + 1);
+}
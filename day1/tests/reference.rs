@@ -0,0 +1,97 @@
+//! Cross-checks both parts against a direct reading of the puzzle.
+
+use proptest::prelude::*;
+
+fn increases(depths: &[u32]) -> u32 {
+    let mut count = 0;
+    for i in 1..depths.len() {
+        if depths[i] > depths[i - 1] {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn window_increases(depths: &[u32]) -> u32 {
+    let mut sums = Vec::new();
+    for i in 2..depths.len() {
+        sums.push(depths[i - 2] + depths[i - 1] + depths[i]);
+    }
+    increases(&sums)
+}
+
+fn lagged_window_increases(depths: &[u32], window: usize, lag: usize) -> u32 {
+    let sums = depths
+        .windows(window)
+        .map(|window| window.iter().sum())
+        .collect::<Vec<u32>>();
+    let mut count = 0;
+    for i in lag..sums.len() {
+        if sums[i] > sums[i - lag] {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn input(depths: &[u32]) -> String {
+    depths.iter().map(|depth| format!("{}\n", depth)).collect()
+}
+
+proptest! {
+    #[test]
+    fn part1_matches_reference(depths in prop::collection::vec(0u32..10_000, 0..200)) {
+        prop_assert_eq!(day1::part1(&input(&depths)).unwrap(), increases(&depths));
+    }
+
+    #[test]
+    fn part2_matches_reference(depths in prop::collection::vec(0u32..10_000, 0..200)) {
+        prop_assert_eq!(day1::part2(&input(&depths)).unwrap(), window_increases(&depths));
+    }
+
+    #[test]
+    fn window_increases_match_reference(
+        depths in prop::collection::vec(0u32..10_000, 0..200),
+        window in 1usize..8,
+        lag in 1usize..8,
+    ) {
+        prop_assert_eq!(
+            day1::count_window_increases(&depths, window, lag),
+            lagged_window_increases(&depths, window, lag)
+        );
+    }
+
+    #[test]
+    fn streaming_matches_reference(
+        depths in prop::collection::vec(0u32..10_000, 0..200),
+        window in 0usize..8,
+        lag in 0usize..8,
+    ) {
+        prop_assert_eq!(
+            day1::stream::count_window_increases::<u32, _>(input(&depths).as_bytes(), window, lag).unwrap(),
+            day1::count_window_increases(&depths, window, lag) as u64
+        );
+        // Floats are summed afresh rather than kept as running sums
+        prop_assert_eq!(
+            day1::stream::count_window_increases::<f64, _>(input(&depths).as_bytes(), window, lag).unwrap(),
+            day1::count_window_increases(&depths, window, lag) as u64
+        );
+    }
+
+    #[test]
+    fn numeric_types_match_unsigned(
+        depths in prop::collection::vec(-10_000i32..10_000, 0..200),
+        window in 1usize..8,
+        lag in 1usize..8,
+    ) {
+        // Shifting every depth by the same amount keeps which sums are larger
+        let unsigned = depths.iter().map(|&depth| (depth + 10_000) as u32).collect::<Vec<_>>();
+        let expected = lagged_window_increases(&unsigned, window, lag);
+
+        prop_assert_eq!(day1::count_window_increases(&depths, window, lag), expected);
+        let wide = depths.iter().map(|&depth| depth as i64 * 1_000_000_000).collect::<Vec<_>>();
+        prop_assert_eq!(day1::count_window_increases(&wide, window, lag), expected);
+        let floats = depths.iter().map(|&depth| depth as f64 / 4.0).collect::<Vec<_>>();
+        prop_assert_eq!(day1::count_window_increases(&floats, window, lag), expected);
+    }
+}
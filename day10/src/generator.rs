@@ -0,0 +1,84 @@
+use common::Rng;
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Deepest nesting, keeping the completion scores of part 2 within a `u64`.
+const MAX_OPEN: usize = 20;
+
+/// Generates `size` lines of chunks, about half of them incomplete and the
+/// rest corrupted. The number of incomplete lines is always odd so part 2
+/// has a middle score.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let incomplete = match size / 2 {
+        half if half % 2 == 1 => half,
+        half => (half + 1).min(size),
+    };
+    let mut kinds = (0..size).map(|i| i < incomplete).collect::<Vec<bool>>();
+    rng.shuffle(&mut kinds);
+
+    let mut input = String::new();
+    for is_incomplete in kinds {
+        input += &line(&mut rng, is_incomplete);
+        input.push('\n');
+    }
+
+    input
+}
+
+/// A random line that is left open at the end, or closed with a wrong
+/// character somewhere in the middle.
+fn line(rng: &mut Rng, incomplete: bool) -> String {
+    let length = rng.range(20..=110) as usize;
+    let corrupt_at = rng.below(length as u64) as usize;
+    let mut open = Vec::new();
+    let mut line = String::new();
+
+    for i in 0..length {
+        if !incomplete && i >= corrupt_at && !open.is_empty() {
+            let expected = open.pop().unwrap();
+            let wrong = PAIRS
+                .iter()
+                .map(|(_, close)| *close)
+                .filter(|close| *close != expected)
+                .collect::<Vec<char>>();
+            line.push(*rng.choose(&wrong));
+            return line;
+        }
+
+        if open.is_empty() || (open.len() < MAX_OPEN && rng.chance(1, 2)) {
+            let (opening, closing) = *rng.choose(&PAIRS);
+            open.push(closing);
+            line.push(opening);
+        } else {
+            line.push(open.pop().unwrap());
+        }
+    }
+
+    if open.is_empty() {
+        let (opening, closing) = *rng.choose(&PAIRS);
+        line.push(opening);
+        open.push(closing);
+    }
+    if !incomplete {
+        let expected = open.pop().unwrap();
+        let (_, wrong) = *PAIRS.iter().find(|(_, close)| *close != expected).unwrap();
+        line.push(wrong);
+    }
+
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(3, 100);
+
+        assert_eq!(input, generate(3, 100));
+        assert_eq!(crate::Day10::parse(&input).unwrap().len(), 100);
+    }
+}
@@ -0,0 +1,218 @@
+pub mod generator;
+
+use std::fmt::Display;
+
+use common::{lint, Lint, Location, Solution};
+
+pub const INPUT: &str = include_str!("../input/day10.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidCharacter(Location),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidCharacter(location) => write!(
+                f,
+                "{}: expected one of `()[]{{}}<>`, found `{}`",
+                location, location.text
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Outcome of checking the chunks of a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineCheckResult {
+    Ok,
+    Corrupted(char),
+    /// The closing characters still expected, innermost last.
+    Incomplete(Vec<char>),
+}
+
+pub fn check_lines(line: &str) -> LineCheckResult {
+    let mut opening = Vec::new();
+    for char in line.trim().chars() {
+        match char {
+            '(' => opening.push(')'),
+            '[' => opening.push(']'),
+            '{' => opening.push('}'),
+            '<' => opening.push('>'),
+            ')' | '}' | ']' | '>' => {
+                // Closing a chunk that was never opened corrupts the line too
+                if opening.pop() != Some(char) {
+                    return LineCheckResult::Corrupted(char);
+                }
+            }
+            // Anything else can't be part of a chunk
+            _ => return LineCheckResult::Corrupted(char),
+        }
+    }
+
+    if opening.is_empty() {
+        return LineCheckResult::Ok;
+    }
+
+    LineCheckResult::Incomplete(opening)
+}
+
+/// Syntax Scoring: scores corrupted and incomplete navigation lines.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Error = ParseError;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let chunks = line.trim();
+                match chunks
+                    .char_indices()
+                    .find(|(_, c)| !"()[]{}<>".contains(*c))
+                {
+                    Some((i, c)) => Err(ParseError::InvalidCharacter(Location::new(
+                        index,
+                        line,
+                        &chunks[i..i + c.len_utf8()],
+                    ))),
+                    None => Ok(chunks.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> u64 {
+        lines
+            .iter()
+            .filter_map(|line| {
+                if let LineCheckResult::Corrupted(char) = check_lines(line) {
+                    return Some(char);
+                }
+
+                None
+            })
+            .map(|x| match x {
+                ')' => 3,
+                ']' => 57,
+                '}' => 1197,
+                '>' => 25137,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn part2(lines: &Self::Input) -> u64 {
+        let mut result = lines
+            .iter()
+            .filter_map(|line| {
+                if let LineCheckResult::Incomplete(chars) = check_lines(line) {
+                    return Some(chars);
+                }
+
+                None
+            })
+            .map(|x| {
+                x.iter()
+                    .rev()
+                    .map(|x| match x {
+                        ')' => 1,
+                        ']' => 2,
+                        '}' => 3,
+                        '>' => 4,
+                        _ => unreachable!("other include any other characters"),
+                    })
+                    // Saturates on lines far longer than the puzzle's
+                    .fold(0u64, |total, item| {
+                        total.saturating_mul(5).saturating_add(item)
+                    })
+            })
+            .collect::<Vec<u64>>();
+        result.sort();
+
+        // Always an odd number of items, if there are any
+        let middle_index = result.len() / 2;
+
+        result.get(middle_index).copied().unwrap_or(0)
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut lints = lint::blank_lines(input);
+        for (index, line) in lint::lines(input) {
+            let chunks = line.trim();
+            if let Some((i, c)) = chunks
+                .char_indices()
+                .find(|(_, c)| !"()[]{}<>".contains(*c))
+            {
+                lints.push(Lint::new(
+                    Location::new(index, line, &chunks[i..i + c.len_utf8()]),
+                    format!("expected one of `()[]{{}}<>`, found `{}`", c),
+                ));
+            }
+        }
+
+        lints
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    Ok(Day10::part1(&Day10::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Ok(Day10::part2(&Day10::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+((((a<>}<{<{<>}{[]{[]{}";
+        let error = part1(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 5: expected one of `()[]{}<>`, found `a`"
+        );
+    }
+
+    #[test]
+    fn test_no_incomplete_lines() {
+        assert_eq!(part2("()\n(]").unwrap(), 0);
+        assert_eq!(part2(&"(".repeat(100)).unwrap(), u64::MAX);
+        assert_eq!(check_lines("(a)"), LineCheckResult::Corrupted('a'));
+    }
+
+    #[test]
+    fn test_large_score() {
+        // Enough corrupted lines that the score doesn't fit in a u32
+        let input = ">\n".repeat(171_000);
+
+        assert_eq!(part1(&input).unwrap(), 171_000 * 25137);
+    }
+
+    #[test]
+    fn test_lint() {
+        let lints = Day10::lint("[({(<(())[]>[[{[]{<()<>>\n\n((((a<>}<{<{<>}{[]{[]{}\n");
+
+        assert_eq!(
+            lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+            [
+                "line 2, column 1: unexpected blank line",
+                "line 3, column 5: expected one of `()[]{}<>`, found `a`",
+            ]
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Cross-checks both parts by repeatedly deleting matched pairs until only
+//! the unmatched characters are left, instead of keeping a stack.
+
+use proptest::prelude::*;
+
+fn reduce(line: &str) -> String {
+    let mut line = line.to_string();
+    loop {
+        let before = line.len();
+        for pair in ["()", "[]", "{}", "<>"] {
+            line = line.replace(pair, "");
+        }
+        if line.len() == before {
+            return line;
+        }
+    }
+}
+
+fn corruption_score(line: &str) -> u64 {
+    match reduce(line).chars().find(|c| ")]}>".contains(*c)) {
+        Some(')') => 3,
+        Some(']') => 57,
+        Some('}') => 1197,
+        Some('>') => 25137,
+        _ => 0,
+    }
+}
+
+fn completion_score(line: &str) -> Option<u64> {
+    let left = reduce(line);
+    if left.is_empty() || left.contains(|c| ")]}>".contains(c)) {
+        return None;
+    }
+    Some(
+        left.chars()
+            .rev()
+            .fold(0, |score, c| score * 5 + "([{<".find(c).unwrap() as u64 + 1),
+    )
+}
+
+proptest! {
+    #[test]
+    fn part1_matches_reference(lines in prop::collection::vec("[()\\[\\]{}<>]{1,30}", 1..20)) {
+        let expected = lines.iter().map(|line| corruption_score(line)).sum::<u64>();
+
+        prop_assert_eq!(day10::part1(&lines.join("\n")).unwrap(), expected);
+    }
+
+    #[test]
+    fn part2_matches_reference(seed in any::<u64>(), size in 1usize..40) {
+        let input = day10::generator::generate(seed, size);
+        let mut scores = input.lines().filter_map(completion_score).collect::<Vec<u64>>();
+        scores.sort_unstable();
+
+        prop_assert_eq!(day10::part2(&input).unwrap(), scores[scores.len() / 2]);
+    }
+}
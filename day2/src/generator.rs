@@ -0,0 +1,39 @@
+use common::Rng;
+
+/// Generates `size` submarine commands. The aim is kept between 0 and 50 so
+/// the submarine never heads above the surface.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut aim = 0;
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let amount = rng.range(1..=9);
+        let command = match rng.below(4) {
+            0 | 1 => "forward",
+            _ if aim + amount > 50 => "up",
+            _ if aim - amount < 0 => "down",
+            2 => "up",
+            _ => "down",
+        };
+        match command {
+            "up" => aim -= amount,
+            "down" => aim += amount,
+            _ => {}
+        }
+
+        input += &format!("{} {}\n", command, amount);
+    }
+
+    input
+}
+
+#[test]
+fn test_generate() {
+    use common::Solution;
+
+    let input = generate(3, 500);
+
+    assert_eq!(input, generate(3, 500));
+    assert_eq!(crate::Day2::parse(&input).unwrap().len(), 500);
+}
@@ -0,0 +1,290 @@
+pub mod generator;
+
+use std::{fmt::Display, str::FromStr};
+
+use common::{lint, Lint, Location, Solution};
+
+pub const INPUT: &str = include_str!("../input/day2.txt");
+
+/// A single instruction for the submarine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandParseError {
+    MissingAmount(Location),
+    UnknownCommand(Location),
+    InvalidAmount(Location),
+}
+
+impl CommandParseError {
+    fn on_line(self, index: usize) -> Self {
+        match self {
+            CommandParseError::MissingAmount(l) => {
+                CommandParseError::MissingAmount(l.on_line(index))
+            }
+            CommandParseError::UnknownCommand(l) => {
+                CommandParseError::UnknownCommand(l.on_line(index))
+            }
+            CommandParseError::InvalidAmount(l) => {
+                CommandParseError::InvalidAmount(l.on_line(index))
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandParseError {}
+
+impl std::fmt::Display for CommandParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CommandParseError::MissingAmount(l) => {
+                write!(
+                    f,
+                    "{}: expected `<command> <amount>`, found `{}`",
+                    l, l.text
+                )
+            }
+            CommandParseError::UnknownCommand(l) => {
+                write!(f, "{}: unknown command `{}`", l, l.text)
+            }
+            CommandParseError::InvalidAmount(l) => write!(f, "{}: invalid amount `{}`", l, l.text),
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = CommandParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command_part, number_part) = s
+            .trim_end()
+            .split_once(' ')
+            .ok_or_else(|| CommandParseError::MissingAmount(Location::line(0, s)))?;
+        let n = number_part
+            .parse()
+            .map_err(|_| CommandParseError::InvalidAmount(Location::new(0, s, number_part)))?;
+
+        match command_part {
+            "forward" => Ok(Command::Forward(n)),
+            "up" => Ok(Command::Up(n)),
+            "down" => Ok(Command::Down(n)),
+            _ => Err(CommandParseError::UnknownCommand(Location::new(
+                0,
+                s,
+                command_part,
+            ))),
+        }
+    }
+}
+
+/// Dive!: follows the planned course of the submarine.
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Command>;
+    type Error = CommandParseError;
+    type Answer1 = Product;
+    type Answer2 = Product;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, x)| x.parse::<Command>().map_err(|e| e.on_line(index)))
+            .collect()
+    }
+
+    fn part1(commands: &Self::Input) -> Product {
+        let (hor, vert) = navigate(commands);
+        Product(hor, vert)
+    }
+
+    fn part2(commands: &Self::Input) -> Product {
+        let (hor, vert) = navigate_with_aim(commands);
+        Product(hor, vert)
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut lints = lint::blank_lines(input);
+        for (index, line) in lint::lines(input) {
+            let Some((command, amount)) = line.trim_end().split_once(' ') else {
+                lints.push(Lint::new(
+                    Location::line(index, line),
+                    format!("expected `<command> <amount>`, found `{}`", line),
+                ));
+                continue;
+            };
+            if !["forward", "down", "up"].contains(&command) {
+                lints.push(Lint::new(
+                    Location::new(index, line, command),
+                    format!("unknown command `{}`", command),
+                ));
+            }
+            if amount.parse::<u32>().is_err() {
+                lints.push(Lint::new(
+                    Location::new(index, line, amount),
+                    format!("expected a non-negative amount, found `{}`", amount),
+                ));
+            }
+        }
+
+        lints
+    }
+}
+
+/// The horizontal position times the depth, kept as both factors because
+/// the product of two `i128`s can need up to 256 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Product(pub i128, pub i128);
+
+impl Display for Product {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+
+        let (a, b) = (self.0.unsigned_abs(), self.1.unsigned_abs());
+        let (a, b) = ([a as u64, (a >> 64) as u64], [b as u64, (b >> 64) as u64]);
+        // Long multiplication into 64-bit limbs, least significant first
+        let mut limbs = [0u64; 4];
+        for i in 0..2 {
+            let mut carry = 0;
+            for j in 0..2 {
+                let limb = a[i] as u128 * b[j] as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = limb as u64;
+                carry = limb >> 64;
+            }
+            limbs[i + 2] = carry as u64;
+        }
+
+        // Long division into 19-digit chunks, least significant first
+        let mut chunks = Vec::new();
+        loop {
+            let mut remainder = 0;
+            for limb in limbs.iter_mut().rev() {
+                let value = remainder << 64 | *limb as u128;
+                *limb = (value / CHUNK) as u64;
+                remainder = value % CHUNK;
+            }
+            chunks.push(remainder);
+            if limbs == [0; 4] {
+                break;
+            }
+        }
+
+        if (self.0 < 0) != (self.1 < 0) && self.0 != 0 && self.1 != 0 {
+            write!(f, "-")?;
+        }
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap_or(&0))?;
+        for chunk in chunks {
+            write!(f, "{:019}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn part1(input: &str) -> Result<(i128, i128), CommandParseError> {
+    Ok(navigate(&Day2::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<(i128, i128), CommandParseError> {
+    Ok(navigate_with_aim(&Day2::parse(input)?))
+}
+
+/// Final `(horizontal position, depth)` when `down` and `up` change the
+/// depth directly.
+pub fn navigate(commands: &[Command]) -> (i128, i128) {
+    commands
+        .iter()
+        .fold((0, 0), |(px, py), command| match *command {
+            Command::Forward(n) => (px + n as i128, py),
+            Command::Up(n) => (px, py - n as i128),
+            Command::Down(n) => (px, py + n as i128),
+        })
+}
+
+/// Final `(horizontal position, depth)` when `down` and `up` change the aim
+/// and `forward` moves along it. Amounts are `i32`, so the depth fits in
+/// `i128` for any course shorter than 2^32 commands.
+pub fn navigate_with_aim(commands: &[Command]) -> (i128, i128) {
+    let (x, y, _aim) = commands
+        .iter()
+        .fold((0, 0, 0i128), |(x, y, aim), command| match *command {
+            Command::Up(n) => (x, y, aim - n as i128),
+            Command::Down(n) => (x, y, aim + n as i128),
+            Command::Forward(n) => (x + n as i128, y + aim * n as i128, aim),
+        });
+    (x, y)
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "forward 5\ndown five";
+
+    let error = part1(input).unwrap_err();
+
+    assert_eq!(error.to_string(), "line 2, column 6: invalid amount `five`");
+}
+
+#[test]
+fn test_large_amounts() {
+    let input = "down 2147483647\nforward 2147483647\nforward 2147483647";
+
+    let (horizontal, depth) = part2(input).unwrap();
+
+    assert_eq!(horizontal, 4294967294);
+    assert_eq!(depth, 9223372028264841218);
+}
+
+#[test]
+fn test_large_product() {
+    let commands = Day2::parse("down 2147483647\nforward 2147483647\nforward 2147483647").unwrap();
+
+    assert_eq!(
+        Day2::part2(&commands).to_string(),
+        (4294967294 * 9223372028264841218i128).to_string()
+    );
+
+    // The depth is 6442450941 squared, which doesn't fit in an i64
+    let input = ["forward 2147483647"; 3]
+        .iter()
+        .chain(&["down 2147483647"; 3])
+        .chain(&["forward 2147483647"; 3])
+        .map(|line| format!("{}\n", line))
+        .collect::<String>();
+    let commands = Day2::parse(&input).unwrap();
+
+    assert_eq!(
+        navigate_with_aim(&commands),
+        (12884901882, 41505174127191785481)
+    );
+    assert_eq!(
+        Day2::part2(&commands).to_string(),
+        "534790096224191144119077175242"
+    );
+    assert_eq!(
+        Product(i128::MIN, i128::MAX).to_string(),
+        "-28948022309329048855892746252171976963147354982949671778132708698262398304256"
+    );
+    assert_eq!(Product(-7, 0).to_string(), "0");
+    assert_eq!(Product(-7, -3).to_string(), "21");
+}
+
+#[test]
+fn test_lint() {
+    let lints = Day2::lint("forward 5\nback -2\ndown\n");
+
+    assert_eq!(
+        lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+        [
+            "line 2, column 1: unknown command `back`",
+            "line 2, column 6: expected a non-negative amount, found `-2`",
+            "line 3, column 1: expected `<command> <amount>`, found `down`",
+        ]
+    );
+}
@@ -0,0 +1,48 @@
+//! Cross-checks both parts against a step by step simulation.
+
+use proptest::prelude::*;
+
+fn commands() -> impl Strategy<Value = Vec<(&'static str, i128)>> {
+    let command = prop::sample::select(vec!["forward", "up", "down"]);
+    prop::collection::vec((command, 0i128..10), 0..60)
+}
+
+fn input(commands: &[(&str, i128)]) -> String {
+    commands
+        .iter()
+        .map(|(command, amount)| format!("{} {}\n", command, amount))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn part1_matches_reference(commands in commands()) {
+        let (mut horizontal, mut depth) = (0, 0);
+        for (command, amount) in &commands {
+            match *command {
+                "forward" => horizontal += amount,
+                "up" => depth -= amount,
+                _ => depth += amount,
+            }
+        }
+
+        prop_assert_eq!(day2::part1(&input(&commands)).unwrap(), (horizontal, depth));
+    }
+
+    #[test]
+    fn part2_matches_reference(commands in commands()) {
+        let (mut horizontal, mut depth, mut aim) = (0, 0, 0);
+        for (command, amount) in &commands {
+            match *command {
+                "forward" => {
+                    horizontal += amount;
+                    depth += aim * amount;
+                }
+                "up" => aim -= amount,
+                _ => aim += amount,
+            }
+        }
+
+        prop_assert_eq!(day2::part2(&input(&commands)).unwrap(), (horizontal, depth));
+    }
+}
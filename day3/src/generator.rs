@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use common::Rng;
+
+/// Generates `size` distinct binary numbers. Duplicates could never be told
+/// apart by the rating filters of part 2, so the width grows with `size`,
+/// starting at the puzzle's 12 bits.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let width = (12..64)
+        .find(|bits| 1u64 << bits >= 2 * size as u64)
+        .unwrap();
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+
+    while seen.len() < size {
+        let number = rng.below(1 << width);
+        if seen.insert(number) {
+            input += &format!("{:0width$b}\n", number, width = width);
+        }
+    }
+
+    input
+}
+
+#[test]
+fn test_generate() {
+    use common::Solution;
+
+    let input = generate(3, 500);
+
+    assert_eq!(input, generate(3, 500));
+    assert_eq!(crate::Day3::parse(&input).unwrap().len(), 500);
+}
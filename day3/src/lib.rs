@@ -0,0 +1,264 @@
+pub mod generator;
+
+use std::fmt::Display;
+
+use common::{lint, Lint, Location, Solution};
+
+pub const INPUT: &str = include_str!("../input/day3.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidBit(Location),
+    WrongLength { location: Location, expected: usize },
+    InvalidWidth(Location),
+    Empty,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidBit(location) => {
+                write!(
+                    f,
+                    "{}: expected `0` or `1`, found `{}`",
+                    location, location.text
+                )
+            }
+            ParseError::WrongLength { location, expected } => write!(
+                f,
+                "{}: expected {} bits like the first line, found `{}`",
+                location, expected, location.text
+            ),
+            ParseError::InvalidWidth(location) => write!(
+                f,
+                "{}: expected 1 to {} bits, found `{}`",
+                location,
+                u32::BITS,
+                location.text
+            ),
+            ParseError::Empty => write!(f, "the report has no numbers"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Binary Diagnostic: decodes power consumption and life support ratings
+/// from the bits of the diagnostic report.
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Vec<u32>>;
+    type Error = ParseError;
+    // Ratings have at most 32 bits, so their product fits in a u64
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut report: Vec<Vec<u32>> = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let bits = line.trim_end();
+            let row = bits
+                .char_indices()
+                .map(|(i, c)| match c {
+                    '0' => Ok(0),
+                    '1' => Ok(1),
+                    _ => Err(ParseError::InvalidBit(Location::new(
+                        index,
+                        line,
+                        &bits[i..i + c.len_utf8()],
+                    ))),
+                })
+                .collect::<Result<Vec<u32>, ParseError>>()?;
+
+            if let Some(first) = report.first() {
+                if first.len() != row.len() {
+                    return Err(ParseError::WrongLength {
+                        location: Location::line(index, bits),
+                        expected: first.len(),
+                    });
+                }
+            } else if row.is_empty() || row.len() > u32::BITS as usize {
+                // The ratings have to fit in the answer
+                return Err(ParseError::InvalidWidth(Location::line(index, bits)));
+            }
+            report.push(row);
+        }
+
+        if report.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(report)
+    }
+
+    fn part1(report: &Self::Input) -> u64 {
+        let ones_count = report
+            .iter()
+            .cloned()
+            .reduce(|counts, bits| counts.iter().zip(bits).map(|(c, b)| c + b).collect())
+            .unwrap_or_default();
+
+        let input_lines_count = report.len();
+        let input_length = ones_count.len();
+        let mut gamma_rate: u64 = 0;
+        let mut epsilon_rate: u64 = 0;
+        let leftmost_bit_position = input_length.saturating_sub(1) as u32;
+
+        for (index, count) in ones_count.iter().enumerate() {
+            // A tied column is neither most nor least common
+            if (*count as usize) * 2 > input_lines_count {
+                gamma_rate += 2u64.pow(leftmost_bit_position - index as u32);
+            }
+            if (*count as usize) * 2 < input_lines_count {
+                epsilon_rate += 2u64.pow(leftmost_bit_position - index as u32);
+            }
+        }
+
+        epsilon_rate * gamma_rate
+    }
+
+    fn part2(report: &Self::Input) -> u64 {
+        let find_rating = |most_common: bool| {
+            let mut left = report.clone();
+            let mut current_position: usize = 0;
+
+            loop {
+                let ones_count = left
+                    .iter()
+                    .fold(0u32, |count, item| count + item[current_position]);
+
+                let bit_to_filter = if ones_count as f64 >= (left.len() as f64 / 2f64) {
+                    if most_common {
+                        1
+                    } else {
+                        0
+                    }
+                } else if most_common {
+                    0
+                } else {
+                    1
+                };
+
+                // When every number shares the bit there is nothing to filter
+                if left.iter().any(|x| x[current_position] == bit_to_filter) {
+                    left.retain(|x| x[current_position] == bit_to_filter);
+                }
+
+                // Duplicate numbers can stay until the last bit
+                if left.len() < 2 || current_position + 1 == report[0].len() {
+                    let answer = left.first().expect("Should be one correct answer");
+
+                    break answer
+                        .iter()
+                        .fold(0u64, |value, &bit| value * 2 + bit as u64);
+                }
+                current_position += 1;
+            }
+        };
+
+        let oxygen_generator_rating = find_rating(true);
+        let co2_scrubber_rating = find_rating(false);
+
+        co2_scrubber_rating * oxygen_generator_rating
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut lints = lint::blank_lines(input);
+        let mut width = None;
+        for (index, line) in lint::lines(input) {
+            let bits = line.trim_end();
+            if let Some((i, c)) = bits.char_indices().find(|(_, c)| !matches!(c, '0' | '1')) {
+                lints.push(Lint::new(
+                    Location::new(index, line, &bits[i..i + c.len_utf8()]),
+                    format!("expected `0` or `1`, found `{}`", c),
+                ));
+            }
+
+            let length = bits.chars().count();
+            match width {
+                None if length > u32::BITS as usize => lints.push(Lint::new(
+                    Location::line(index, bits),
+                    format!("expected at most {} bits, found {}", u32::BITS, length),
+                )),
+                Some(width) if width != length => lints.push(Lint::new(
+                    Location::line(index, bits),
+                    format!(
+                        "expected {} bits like the first line, found {}",
+                        width, length
+                    ),
+                )),
+                _ => {}
+            }
+            width.get_or_insert(length);
+        }
+
+        lints
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64, ParseError> {
+    Ok(Day3::part1(&Day3::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Ok(Day3::part2(&Day3::parse(input)?))
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "00100\n11120\n10110";
+
+    let error = part1(input).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 4: expected `0` or `1`, found `2`"
+    );
+}
+
+#[test]
+fn test_invalid_report() {
+    assert_eq!(part1("").unwrap_err(), ParseError::Empty);
+    assert_eq!(
+        part1("\n0").unwrap_err().to_string(),
+        "line 1, column 1: expected 1 to 32 bits, found ``"
+    );
+    assert!(part1(&"1".repeat(33)).is_err());
+}
+
+#[test]
+fn test_wide_report() {
+    let input = "111111111111000000000000\n111111111111000000000000\n000000000000111111111111";
+
+    assert_eq!(part1(input).unwrap(), 0xfff000 * 0xfff);
+    assert_eq!(part2(input).unwrap(), 0xfff000 * 0xfff);
+
+    let high = format!("{}{}\n", "1".repeat(16), "0".repeat(16));
+    let input = format!("{}{}{}{}", high, high, "0".repeat(16), "1".repeat(16));
+
+    assert_eq!(part1(&input).unwrap(), 0xffff0000 * 0xffff);
+    assert_eq!(part2(&input).unwrap(), 0xffff0000 * 0xffff);
+}
+
+#[test]
+fn test_duplicate_numbers() {
+    let input = "10110\n10110\n01000";
+
+    assert_eq!(part2(input).unwrap(), 22 * 8);
+}
+
+#[test]
+fn test_lint() {
+    let lints = Day3::lint("00100\n1112\n\n10110\n");
+
+    assert_eq!(
+        lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+        [
+            "line 3, column 1: unexpected blank line",
+            "line 2, column 4: expected `0` or `1`, found `2`",
+            "line 2, column 1: expected 5 bits like the first line, found 4",
+        ]
+    );
+}
@@ -0,0 +1,68 @@
+//! Cross-checks both parts against the rating rules applied to plain
+//! integers. A tied column counts as neither most nor least common in part 1.
+
+use proptest::prelude::*;
+
+/// Distinct numbers of a random width, as the puzzle's reports are.
+fn report() -> impl Strategy<Value = (usize, Vec<u32>)> {
+    (1usize..=10).prop_flat_map(|width| {
+        let all = (0..1u32 << width).collect::<Vec<u32>>();
+        let most = all.len().min(40);
+        let numbers = prop::sample::subsequence(all, 1..=most).prop_shuffle();
+        (Just(width), numbers)
+    })
+}
+
+fn input(width: usize, numbers: &[u32]) -> String {
+    numbers
+        .iter()
+        .map(|number| format!("{:0width$b}\n", number, width = width))
+        .collect()
+}
+
+fn ones(numbers: &[u32], bit: usize) -> usize {
+    numbers
+        .iter()
+        .filter(|number| *number >> bit & 1 == 1)
+        .count()
+}
+
+fn rating(width: usize, numbers: &[u32], most_common: bool) -> u32 {
+    let mut left = numbers.to_vec();
+    for bit in (0..width).rev() {
+        if left.len() == 1 {
+            break;
+        }
+        let ones_are_common = ones(&left, bit) * 2 >= left.len();
+        let keep = (ones_are_common == most_common) as u32;
+        if left.iter().any(|number| number >> bit & 1 == keep) {
+            left.retain(|number| number >> bit & 1 == keep);
+        }
+    }
+    left[0]
+}
+
+proptest! {
+    #[test]
+    fn part1_matches_reference((width, numbers) in report()) {
+        let (mut gamma, mut epsilon) = (0, 0);
+        for bit in 0..width {
+            let ones = ones(&numbers, bit) * 2;
+            if ones > numbers.len() {
+                gamma |= 1 << bit;
+            }
+            if ones < numbers.len() {
+                epsilon |= 1 << bit;
+            }
+        }
+
+        prop_assert_eq!(day3::part1(&input(width, &numbers)).unwrap(), gamma * epsilon);
+    }
+
+    #[test]
+    fn part2_matches_reference((width, numbers) in report()) {
+        let expected = rating(width, &numbers, true) as u64 * rating(width, &numbers, false) as u64;
+
+        prop_assert_eq!(day3::part2(&input(width, &numbers)).unwrap(), expected);
+    }
+}
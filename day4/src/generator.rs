@@ -0,0 +1,43 @@
+use common::Rng;
+
+use crate::SIZE;
+
+/// Generates a bingo game with `size` boards. Every number from 0 to 99 is
+/// drawn once, so every board eventually wins.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut numbers = (0..100).collect::<Vec<u8>>();
+
+    rng.shuffle(&mut numbers);
+    let mut input = numbers
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    input.push('\n');
+
+    for _ in 0..size {
+        rng.shuffle(&mut numbers);
+        input.push('\n');
+        for row in numbers[..SIZE * SIZE].chunks(SIZE) {
+            let row = row
+                .iter()
+                .map(|x| format!("{:>2}", x))
+                .collect::<Vec<String>>();
+            input += &row.join(" ");
+            input.push('\n');
+        }
+    }
+
+    input
+}
+
+#[test]
+fn test_generate() {
+    use common::Solution;
+
+    let input = generate(3, 50);
+
+    assert_eq!(input, generate(3, 50));
+    assert_eq!(crate::Day4::parse(&input).unwrap().1.len(), 50);
+}
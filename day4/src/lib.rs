@@ -0,0 +1,421 @@
+pub mod generator;
+
+use std::fmt::Display;
+
+use common::{lint, Grid, Lint, Location, Solution};
+
+pub const INPUT: &str = include_str!("../input/day4.txt");
+
+/// Width and height of a bingo board.
+pub const SIZE: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingNumbers,
+    MissingBlankLine(Location),
+    InvalidNumber(Location),
+    WrongRowLength(Location),
+    WrongBoardSize(Location),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::MissingNumbers => write!(f, "missing the line of drawn numbers"),
+            ParseError::MissingBlankLine(location) => write!(
+                f,
+                "{}: expected a blank line after the drawn numbers, found `{}`",
+                location, location.text
+            ),
+            ParseError::InvalidNumber(location) => {
+                write!(f, "{}: invalid number `{}`", location, location.text)
+            }
+            ParseError::WrongRowLength(location) => write!(
+                f,
+                "{}: expected {} numbers in a board row, found `{}`",
+                location, SIZE, location.text
+            ),
+            ParseError::WrongBoardSize(location) => write!(
+                f,
+                "{}: expected the board starting here to have {} rows",
+                location, SIZE
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A bingo board with a marked flag per number.
+#[derive(Debug, Clone)]
+pub struct Board {
+    won: bool,
+    values: Grid<(bool, u8)>,
+}
+
+impl Board {
+    pub fn new() -> Self {
+        Self {
+            won: false,
+            values: Grid::with_width(SIZE),
+        }
+    }
+
+    /// Appends a row of `SIZE` numbers.
+    pub fn add_row(&mut self, row: Vec<(bool, u8)>) {
+        self.values.push_row(row);
+    }
+
+    pub fn has_won(&self) -> bool {
+        self.won
+    }
+
+    /// Marks `val` if it is on the board.
+    pub fn add_drawed_value(&mut self, val: u8) {
+        if let Some(elem) = self.values.values_mut().find(|(_, num)| val == *num) {
+            elem.0 = true;
+        }
+    }
+
+    /// Records a win once a full row or column is marked.
+    pub fn check(&mut self) {
+        if self.won {
+            return;
+        }
+
+        self.won = self
+            .values
+            .rows()
+            .any(|row| row.iter().all(|(checked, _)| *checked))
+            || self
+                .values
+                .columns()
+                .any(|mut column| column.all(|(checked, _)| *checked));
+    }
+
+    /// Sum of the unmarked numbers.
+    pub fn remaining_values(&self) -> u32 {
+        self.values
+            .values()
+            .filter_map(|(check, val)| if *check { None } else { Some(*val as u32) })
+            .sum()
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Giant Squid: finds the first and the last bingo board to win.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = (Vec<u8>, Vec<Board>);
+    type Error = ParseError;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_values_and_board(input)
+    }
+
+    fn part1((values, boards): &Self::Input) -> u32 {
+        let mut boards = boards.clone();
+
+        for value in values.iter() {
+            for board in boards.iter_mut() {
+                board.add_drawed_value(*value);
+                board.check();
+
+                if board.has_won() {
+                    return board.remaining_values() * *value as u32;
+                }
+            }
+        }
+
+        // No board wins with the numbers drawn
+        0
+    }
+
+    fn part2((values, boards): &Self::Input) -> u32 {
+        let mut boards = boards.clone();
+
+        let mut last_result = 0;
+        for current_value in values {
+            for board in boards.iter_mut() {
+                board.add_drawed_value(*current_value);
+                board.check();
+
+                if board.has_won() {
+                    last_result = board.remaining_values() * *current_value as u32;
+                }
+            }
+
+            boards.retain(|b| !b.has_won());
+
+            if boards.is_empty() {
+                break;
+            }
+        }
+
+        // The last board to win, even if some never do
+        last_result
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        let mut lines = input.lines().enumerate();
+        if let Some((index, line)) = lines.next() {
+            let mut drawn = Vec::new();
+            for text in line.split(',').map(str::trim) {
+                match lint_number(index, line, text, &mut lints) {
+                    Some(value) if drawn.contains(&value) => lints.push(Lint::new(
+                        Location::new(index, line, text),
+                        format!("`{}` is drawn twice", text),
+                    )),
+                    Some(value) => drawn.push(value),
+                    None => {}
+                }
+            }
+        }
+        if let Some((index, line)) = lines.next() {
+            if !line.trim().is_empty() {
+                lints.push(Lint::new(
+                    Location::line(index, line),
+                    "expected a blank line after the drawn numbers",
+                ));
+            }
+        }
+
+        let last = lint::lines(input).last().map_or(0, |(index, _)| index);
+        let mut board: Vec<(usize, &str)> = Vec::new();
+        let mut blank_before = true;
+        for (index, line) in lines.take_while(|(index, _)| *index <= last) {
+            if !line.trim().is_empty() {
+                board.push((index, line));
+                blank_before = false;
+                continue;
+            }
+            if blank_before {
+                lints.push(Lint::new(
+                    Location::line(index, line),
+                    "unexpected blank line",
+                ));
+            }
+            lint_board(&board, &mut lints);
+            board.clear();
+            blank_before = true;
+        }
+        lint_board(&board, &mut lints);
+
+        lints
+    }
+}
+
+/// The number `text` on the line, if it is one from 0 to 99.
+fn lint_number(index: usize, line: &str, text: &str, lints: &mut Vec<Lint>) -> Option<u8> {
+    match text.parse::<u8>() {
+        Ok(value) if value <= 99 => Some(value),
+        _ => {
+            lints.push(Lint::new(
+                Location::new(index, line, text),
+                format!("expected a number from 0 to 99, found `{}`", text),
+            ));
+            None
+        }
+    }
+}
+
+/// Checks the size of the board made of `rows` and that its numbers are in
+/// range and distinct.
+fn lint_board(rows: &[(usize, &str)], lints: &mut Vec<Lint>) {
+    let Some(&(start, first)) = rows.first() else {
+        return;
+    };
+    if rows.len() != SIZE {
+        lints.push(Lint::new(
+            Location::line(start, first),
+            format!(
+                "expected the board starting here to have {} rows, found {}",
+                SIZE,
+                rows.len()
+            ),
+        ));
+    }
+
+    let mut seen = Vec::new();
+    for &(index, line) in rows {
+        let numbers = line.split_whitespace().collect::<Vec<&str>>();
+        if numbers.len() != SIZE {
+            lints.push(Lint::new(
+                Location::line(index, line),
+                format!(
+                    "expected {} numbers in a board row, found {}",
+                    SIZE,
+                    numbers.len()
+                ),
+            ));
+        }
+        for text in numbers {
+            match lint_number(index, line, text, lints) {
+                Some(value) if seen.contains(&value) => lints.push(Lint::new(
+                    Location::new(index, line, text),
+                    format!("`{}` is on the board twice", text),
+                )),
+                Some(value) => seen.push(value),
+                None => {}
+            }
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    Ok(Day4::part1(&Day4::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    Ok(Day4::part2(&Day4::parse(input)?))
+}
+
+/// Parses the drawn numbers and the boards that follow them.
+pub fn parse_values_and_board(input: &str) -> Result<(Vec<u8>, Vec<Board>), ParseError> {
+    let mut input_iter = input.lines().enumerate();
+
+    let (index, line) = input_iter.next().ok_or(ParseError::MissingNumbers)?;
+    let values = line
+        .split(',')
+        .map(|x| parse_number(index, line, x.trim()))
+        .collect::<Result<Vec<u8>, ParseError>>()?;
+
+    if let Some((index, line)) = input_iter.next() {
+        if !line.trim().is_empty() {
+            return Err(ParseError::MissingBlankLine(Location::line(index, line)));
+        }
+    }
+
+    let mut boards = Vec::new();
+    let mut current_board: Board = Board::new();
+    let mut board_start = None;
+    for (index, line) in input_iter {
+        if line.trim().is_empty() {
+            if let Some(start) = board_start.take() {
+                check_board_size(&current_board, start, input)?;
+                boards.push(current_board);
+                current_board = Board::new();
+            }
+        } else {
+            board_start.get_or_insert(index);
+            let row = line
+                .split_whitespace()
+                .map(|x| Ok((false, parse_number(index, line, x)?)))
+                .collect::<Result<Vec<(bool, u8)>, ParseError>>()?;
+            if row.len() != SIZE {
+                return Err(ParseError::WrongRowLength(Location::line(index, line)));
+            }
+            current_board.add_row(row);
+        }
+    }
+    if let Some(start) = board_start {
+        check_board_size(&current_board, start, input)?;
+        boards.push(current_board);
+    }
+
+    Ok((values, boards))
+}
+
+fn parse_number(index: usize, line: &str, number: &str) -> Result<u8, ParseError> {
+    number
+        .parse()
+        .map_err(|_| ParseError::InvalidNumber(Location::new(index, line, number)))
+}
+
+fn check_board_size(board: &Board, start: usize, input: &str) -> Result<(), ParseError> {
+    if board.values.height() == SIZE {
+        return Ok(());
+    }
+
+    let line = input.lines().nth(start).unwrap_or_default();
+    Err(ParseError::WrongBoardSize(Location::line(start, line)))
+}
+
+#[test]
+fn test_parse_error() {
+    let input = "7,4,9
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16
+ 6 10  3 18  5
+ 1 12 20 15 19";
+
+    let error = part1(input).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "line 5, column 1: expected 5 numbers in a board row, found `21  9 14 16`"
+    );
+}
+
+#[test]
+fn test_missing_blank_line() {
+    let input = "7,4,9
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19";
+
+    let error = part1(input).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "line 2, column 1: expected a blank line after the drawn numbers, found `22 13 17 11  0`"
+    );
+}
+
+#[test]
+fn test_no_winner() {
+    let input = "7,4
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19";
+
+    assert_eq!(part1(input).unwrap(), 0);
+    assert_eq!(part2(input).unwrap(), 0);
+    assert_eq!(part2("").unwrap_err(), ParseError::MissingNumbers);
+}
+
+#[test]
+fn test_lint() {
+    let input = "7,4,100,7
+
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16
+ 6 10  3 18  5
+ 1 12 20 15 13
+
+ 3 15  0  2 22
+";
+
+    assert_eq!(
+        Day4::lint(input)
+            .iter()
+            .map(Lint::to_string)
+            .collect::<Vec<_>>(),
+        [
+            "line 1, column 5: expected a number from 0 to 99, found `100`",
+            "line 1, column 9: `7` is drawn twice",
+            "line 3, column 1: unexpected blank line",
+            "line 6, column 1: expected 5 numbers in a board row, found 4",
+            "line 8, column 13: `13` is on the board twice",
+            "line 10, column 1: expected the board starting here to have 5 rows, found 1",
+        ]
+    );
+}
@@ -0,0 +1,82 @@
+//! Cross-checks both parts by working out when each board wins from the
+//! draw order alone, instead of marking numbers round by round.
+
+use proptest::prelude::*;
+
+type Board = Vec<u8>;
+
+/// Every number below some limit drawn in random order, so all boards win,
+/// and boards of distinct numbers below that limit.
+fn game() -> impl Strategy<Value = (Vec<u8>, Vec<Board>)> {
+    (25u8..60).prop_flat_map(|limit| {
+        let numbers = (0..limit).collect::<Vec<u8>>();
+        let board = prop::sample::subsequence(numbers.clone(), 25).prop_shuffle();
+        (
+            Just(numbers).prop_shuffle(),
+            prop::collection::vec(board, 1..6),
+        )
+    })
+}
+
+fn input(draws: &[u8], boards: &[Board]) -> String {
+    let mut input = draws
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<String>>()
+        .join(",");
+    input.push('\n');
+    for board in boards {
+        input.push('\n');
+        for row in board.chunks(5) {
+            let row = row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+/// The draw after which `board` has a full row or column, and its score.
+fn win(draws: &[u8], board: &Board) -> (usize, u32) {
+    let turn = |number: &u8| draws.iter().position(|draw| draw == number).unwrap();
+    let lines = (0..5)
+        .map(|i| (0..5).map(|j| board[i * 5 + j]).collect::<Vec<u8>>())
+        .chain((0..5).map(|j| (0..5).map(|i| board[i * 5 + j]).collect()));
+    let won = lines
+        .map(|line| line.iter().map(turn).max().unwrap())
+        .min()
+        .unwrap();
+
+    let unmarked = board
+        .iter()
+        .filter(|number| turn(number) > won)
+        .map(|number| *number as u32)
+        .sum::<u32>();
+    (won, unmarked * draws[won] as u32)
+}
+
+proptest! {
+    #[test]
+    fn part1_matches_reference((draws, boards) in game()) {
+        // The first board in order wins a tie
+        let (_, score) = boards
+            .iter()
+            .map(|board| win(&draws, board))
+            .min_by_key(|(won, _)| *won)
+            .unwrap();
+
+        prop_assert_eq!(day4::part1(&input(&draws, &boards)).unwrap(), score);
+    }
+
+    #[test]
+    fn part2_matches_reference((draws, boards) in game()) {
+        // The last board in order wins a tie
+        let (_, score) = boards
+            .iter()
+            .map(|board| win(&draws, board))
+            .max_by_key(|(won, _)| *won)
+            .unwrap();
+
+        prop_assert_eq!(day4::part2(&input(&draws, &boards)).unwrap(), score);
+    }
+}
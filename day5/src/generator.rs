@@ -0,0 +1,50 @@
+use common::Rng;
+
+/// Largest coordinate, matching the puzzle's 1000x1000 ocean floor.
+const MAX: i64 = 999;
+
+/// Generates `size` horizontal, vertical and diagonal vent lines.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let (x1, y1) = (rng.range(0..=MAX), rng.range(0..=MAX));
+        let (dx, dy) = *rng.choose(&[
+            (1, 0),
+            (-1, 0),
+            (0, 1),
+            (0, -1),
+            (1, 1),
+            (1, -1),
+            (-1, 1),
+            (-1, -1),
+        ]);
+        let room = |start: i64, delta: i64| match delta {
+            1 => MAX - start,
+            -1 => start,
+            _ => MAX,
+        };
+        let length = rng.range(0..=room(x1, dx).min(room(y1, dy)).min(500));
+
+        input += &format!(
+            "{},{} -> {},{}\n",
+            x1,
+            y1,
+            x1 + dx * length,
+            y1 + dy * length
+        );
+    }
+
+    input
+}
+
+#[test]
+fn test_generate() {
+    use common::Solution;
+
+    let input = generate(3, 500);
+
+    assert_eq!(input, generate(3, 500));
+    assert_eq!(crate::Day5::parse(&input).unwrap().len(), 500);
+}
@@ -0,0 +1,331 @@
+pub mod generator;
+
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+    fmt::Display,
+    str::FromStr,
+};
+
+use common::{lint, Grid, Lint, Location, Point, Solution};
+
+pub const INPUT: &str = include_str!("../input/day5.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineParseError {
+    MissingArrow(Location),
+    MissingComma(Location),
+    InvalidCoordinate(Location),
+}
+
+impl LineParseError {
+    fn on_line(self, index: usize) -> Self {
+        match self {
+            LineParseError::MissingArrow(l) => LineParseError::MissingArrow(l.on_line(index)),
+            LineParseError::MissingComma(l) => LineParseError::MissingComma(l.on_line(index)),
+            LineParseError::InvalidCoordinate(l) => {
+                LineParseError::InvalidCoordinate(l.on_line(index))
+            }
+        }
+    }
+}
+
+impl LineParseError {
+    fn location(&self) -> &Location {
+        match self {
+            LineParseError::MissingArrow(l)
+            | LineParseError::MissingComma(l)
+            | LineParseError::InvalidCoordinate(l) => l,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            LineParseError::MissingArrow(l) => {
+                format!("expected `x1,y1 -> x2,y2`, found `{}`", l.text)
+            }
+            LineParseError::MissingComma(l) => format!("expected `x,y`, found `{}`", l.text),
+            LineParseError::InvalidCoordinate(l) => format!("invalid coordinate `{}`", l.text),
+        }
+    }
+}
+
+impl Display for LineParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location(), self.message())
+    }
+}
+
+impl std::error::Error for LineParseError {}
+
+/// A line of hydrothermal vents from `start` to `end`, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    start: (u32, u32),
+    end: (u32, u32),
+}
+
+impl Line {
+    pub fn new(start: (u32, u32), end: (u32, u32)) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> (u32, u32) {
+        self.start
+    }
+
+    pub fn end(&self) -> (u32, u32) {
+        self.end
+    }
+
+    pub fn horizontal_or_vertical(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.1 == self.end.1
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.0 == self.end.0
+    }
+
+    /// Every point on the line, which is horizontal, vertical or at 45
+    /// degrees.
+    pub fn covered_points<'a>(&'a self) -> Box<dyn Iterator<Item = (u32, u32)> + 'a> {
+        if self.is_vertical() {
+            return Box::new(
+                (min(self.start.1, self.end.1)..=max(self.start.1, self.end.1))
+                    .map(|y| (self.start.0, y)),
+            );
+        }
+        if self.is_horizontal() {
+            return Box::new(
+                (min(self.start.0, self.end.0)..=max(self.start.0, self.end.0))
+                    .map(|x| (x, self.start.1)),
+            );
+        }
+
+        let get_range = |start, end| -> Box<dyn Iterator<Item = u32>> {
+            if start < end {
+                Box::new(start..=end)
+            } else {
+                Box::new((end..=start).rev())
+            }
+        };
+
+        let x_values = get_range(self.start.0, self.end.0);
+        let y_values = get_range(self.start.1, self.end.1);
+
+        Box::new(x_values.zip(y_values))
+    }
+}
+
+impl FromStr for Line {
+    type Err = LineParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let point = |part: &str| -> Result<(u32, u32), LineParseError> {
+            let (x, y) = part
+                .split_once(',')
+                .ok_or_else(|| LineParseError::MissingComma(Location::new(0, s, part)))?;
+            let coordinate = |value: &str| {
+                value
+                    .parse()
+                    .map_err(|_| LineParseError::InvalidCoordinate(Location::new(0, s, value)))
+            };
+
+            Ok((coordinate(x)?, coordinate(y)?))
+        };
+
+        let (start, end) = s
+            .trim_end()
+            .split_once(" -> ")
+            .ok_or_else(|| LineParseError::MissingArrow(Location::line(0, s)))?;
+
+        Ok(Self {
+            start: point(start)?,
+            end: point(end)?,
+        })
+    }
+}
+
+/// Hydrothermal Venture: counts the points where vent lines overlap.
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Line>;
+    type Error = LineParseError;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, x)| x.parse::<Line>().map_err(|e| e.on_line(index)))
+            .collect()
+    }
+
+    fn part1(lines: &Self::Input) -> u64 {
+        let lines = lines.iter().filter(|l| l.horizontal_or_vertical());
+
+        count_covered_points(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> u64 {
+        count_covered_points(lines.iter())
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut lints = lint::blank_lines(input);
+        for (index, text) in lint::lines(input) {
+            match text.parse::<Line>() {
+                Ok(line) => {
+                    let (dx, dy) = (
+                        line.start.0.abs_diff(line.end.0),
+                        line.start.1.abs_diff(line.end.1),
+                    );
+                    if dx != 0 && dy != 0 && dx != dy {
+                        lints.push(Lint::new(
+                            Location::line(index, text),
+                            "expected a horizontal, vertical or 45 degree line",
+                        ));
+                    }
+                }
+                Err(error) => {
+                    let error = error.on_line(index);
+                    lints.push(Lint::new(error.location().clone(), error.message()));
+                }
+            }
+        }
+
+        lints
+    }
+}
+
+pub fn part1(input: &str) -> Result<u64, LineParseError> {
+    Ok(Day5::part1(&Day5::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<u64, LineParseError> {
+    Ok(Day5::part2(&Day5::parse(input)?))
+}
+
+/// Counts the points covered by at least two of `lines`. The points are
+/// counted on a map of the area the lines span, or point by point when the
+/// lines are too far apart for that map to fit.
+pub fn count_covered_points<'a>(lines: impl Iterator<Item = &'a Line>) -> u64 {
+    let lines = lines.collect::<Vec<&Line>>();
+    let Some((left, top, right, bottom)) = lines
+        .iter()
+        .map(|line| {
+            (
+                min(line.start.0, line.end.0),
+                min(line.start.1, line.end.1),
+                max(line.start.0, line.end.0),
+                max(line.start.1, line.end.1),
+            )
+        })
+        .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))
+    else {
+        return 0;
+    };
+
+    let (width, height) = ((right - left) as usize + 1, (bottom - top) as usize + 1);
+    let points = lines.iter().fold(0usize, |points, line| {
+        let length = line
+            .start
+            .0
+            .abs_diff(line.end.0)
+            .max(line.start.1.abs_diff(line.end.1));
+        points.saturating_add(length as usize + 1)
+    });
+    // A map several times larger than the lines themselves isn't worth it
+    let dense = width
+        .checked_mul(height)
+        .is_some_and(|area| area <= points.saturating_mul(8).max(1 << 20));
+
+    if dense {
+        let mut map = Grid::new(width, height, 0u8);
+        for line in lines {
+            for (x, y) in line.covered_points() {
+                let count = &mut map[Point((x - left) as isize, (y - top) as isize)];
+                *count = count.saturating_add(1);
+            }
+        }
+
+        map.values().filter(|x| **x > 1).count() as u64
+    } else {
+        let mut counts = HashMap::new();
+        for line in lines {
+            for point in line.covered_points() {
+                let count = counts.entry(point).or_insert(0u8);
+                *count = count.saturating_add(1);
+            }
+        }
+
+        counts.values().filter(|x| **x > 1).count() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = part1("0,9 -> 5,9\n8,0 -> 0,x").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 10: invalid coordinate `x`"
+        )
+    }
+
+    #[test]
+    fn test_orientation() {
+        let horizontal = Line::new((0, 9), (5, 9));
+        let vertical = Line::new((7, 0), (7, 4));
+        let diagonal = Line::new((1, 1), (3, 3));
+
+        assert!(horizontal.is_horizontal() && !horizontal.is_vertical());
+        assert!(vertical.is_vertical() && !vertical.is_horizontal());
+        assert!(!diagonal.horizontal_or_vertical());
+        assert_eq!(vertical.covered_points().count(), 5);
+    }
+
+    #[test]
+    fn test_lint() {
+        let lints = Day5::lint("0,9 -> 5,9\n0,0 -> 2,5\n1,1 - 3,3\n1,x -> 3,3\n");
+
+        assert_eq!(
+            lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+            [
+                "line 2, column 1: expected a horizontal, vertical or 45 degree line",
+                "line 3, column 1: expected `x1,y1 -> x2,y2`, found `1,1 - 3,3`",
+                "line 4, column 3: invalid coordinate `x`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_large_coordinates() {
+        let input = "4000000000,9 -> 4000000005,9\n4000000002,0 -> 4000000002,20\n";
+
+        assert_eq!(part1(input).unwrap(), 1);
+
+        // Too far apart for a map of the whole area
+        let input = "0,0 -> 2,2\n4294967295,4294967295 -> 4294967293,4294967293\n2,0 -> 0,2";
+
+        assert_eq!(part1(input).unwrap(), 0);
+        assert_eq!(part2(input).unwrap(), 1);
+
+        let error = part2("0,9 -> 4294967296,9").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 8: invalid coordinate `4294967296`"
+        )
+    }
+}
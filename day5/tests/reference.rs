@@ -0,0 +1,59 @@
+//! Cross-checks both parts by stepping along every line into a map of
+//! counts.
+
+use std::collections::HashMap;
+
+use proptest::prelude::*;
+
+type Line = ((i32, i32), (i32, i32));
+
+/// A horizontal, vertical or diagonal line, possibly a single point.
+fn line() -> impl Strategy<Value = Line> {
+    let step = prop::sample::select(vec![
+        (1, 0),
+        (-1, 0),
+        (0, 1),
+        (0, -1),
+        (1, 1),
+        (1, -1),
+        (-1, 1),
+        (-1, -1),
+    ]);
+    (10i32..20, 10i32..20, step, 0i32..10)
+        .prop_map(|(x, y, (dx, dy), length)| ((x, y), (x + dx * length, y + dy * length)))
+}
+
+fn input(lines: &[Line]) -> String {
+    lines
+        .iter()
+        .map(|((x1, y1), (x2, y2))| format!("{},{} -> {},{}\n", x1, y1, x2, y2))
+        .collect()
+}
+
+fn overlaps<'a>(lines: impl Iterator<Item = &'a Line>) -> u64 {
+    let mut counts = HashMap::new();
+    for &((x1, y1), (x2, y2)) in lines {
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        let length = (x2 - x1).abs().max((y2 - y1).abs());
+        for i in 0..=length {
+            *counts.entry((x1 + dx * i, y1 + dy * i)).or_insert(0) += 1;
+        }
+    }
+    counts.values().filter(|count| **count > 1).count() as u64
+}
+
+proptest! {
+    #[test]
+    fn part1_matches_reference(lines in prop::collection::vec(line(), 0..30)) {
+        let straight = lines
+            .iter()
+            .filter(|((x1, y1), (x2, y2))| x1 == x2 || y1 == y2);
+
+        prop_assert_eq!(day5::part1(&input(&lines)).unwrap(), overlaps(straight));
+    }
+
+    #[test]
+    fn part2_matches_reference(lines in prop::collection::vec(line(), 0..30)) {
+        prop_assert_eq!(day5::part2(&input(&lines)).unwrap(), overlaps(lines.iter()));
+    }
+}
@@ -0,0 +1,25 @@
+use common::Rng;
+
+/// Generates `size` lanternfish with timers from 1 to 5, like the puzzle.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let timers = (0..size)
+        .map(|_| rng.range(1..=5).to_string())
+        .collect::<Vec<String>>();
+
+    timers.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(3, 500);
+
+        assert_eq!(input, generate(3, 500));
+        assert_eq!(crate::Day6::parse(&input).unwrap().len(), 500);
+    }
+}
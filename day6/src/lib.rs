@@ -0,0 +1,139 @@
+pub mod generator;
+
+use std::fmt::Display;
+
+use common::{lint, Lint, Location, Solution};
+
+pub const INPUT: &str = include_str!("../input/day6.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidTimer(Location),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidTimer(location) => {
+                write!(
+                    f,
+                    "{}: expected a timer from 0 to 8, found `{}`",
+                    location, location.text
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Lanternfish: counts the fish after 80 and 256 days.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .flat_map(|(index, line)| line.split(',').map(move |x| (index, line, x.trim())))
+            .map(|(index, line, x)| match x.parse() {
+                Ok(timer) if timer <= 8 => Ok(timer),
+                _ => Err(ParseError::InvalidTimer(Location::new(index, line, x))),
+            })
+            .collect()
+    }
+
+    fn part1(fishes: &Self::Input) -> usize {
+        emulate_lanterfish_and_count(fishes, 80)
+    }
+
+    fn part2(fishes: &Self::Input) -> usize {
+        emulate_lanterfish_and_count(fishes, 256)
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        for (count, (index, line)) in lint::lines(input).enumerate() {
+            if count > 0 {
+                lints.push(Lint::new(
+                    Location::line(index, line),
+                    "expected all timers on the first line",
+                ));
+            }
+            for value in line.split(',').map(str::trim) {
+                if !matches!(value.parse::<u8>(), Ok(timer) if timer <= 8) {
+                    lints.push(Lint::new(
+                        Location::new(index, line, value),
+                        format!("expected a timer from 0 to 8, found `{}`", value),
+                    ));
+                }
+            }
+        }
+
+        lints
+    }
+}
+
+/// Number of fish after `days`, starting from the given timers.
+pub fn emulate_lanterfish_and_count(fishes: &[usize], days: u32) -> usize {
+    let mut collection: [usize; 9] = [0; 9];
+
+    for fish in fishes {
+        collection[*fish] += 1;
+    }
+
+    for _ in 0..days {
+        let reset = collection[0];
+        collection[0] = 0;
+
+        for i in 1..9 {
+            collection[i - 1] = collection[i];
+        }
+
+        collection[6] += reset;
+        collection[8] = reset;
+    }
+
+    collection.iter().sum()
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(Day6::part1(&Day6::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(Day6::part2(&Day6::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = part1("3,4,9,1,2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected a timer from 0 to 8, found `9`"
+        );
+    }
+    #[test]
+    fn test_lint() {
+        let lints = Day6::lint("3,4,9,1,2\n\n3,x\n");
+
+        assert_eq!(
+            lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+            [
+                "line 1, column 5: expected a timer from 0 to 8, found `9`",
+                "line 3, column 1: expected all timers on the first line",
+                "line 3, column 3: expected a timer from 0 to 8, found `x`",
+            ]
+        );
+    }
+}
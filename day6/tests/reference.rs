@@ -0,0 +1,45 @@
+//! Cross-checks the bucketed simulation against one that tracks every fish.
+
+use proptest::prelude::*;
+
+fn simulate(timers: &[usize], days: u32) -> usize {
+    let mut fishes = timers.to_vec();
+    for _ in 0..days {
+        let mut born = 0;
+        for timer in fishes.iter_mut() {
+            if *timer == 0 {
+                *timer = 6;
+                born += 1;
+            } else {
+                *timer -= 1;
+            }
+        }
+        fishes.extend(std::iter::repeat_n(8, born));
+    }
+    fishes.len()
+}
+
+fn timers() -> impl Strategy<Value = Vec<usize>> {
+    prop::collection::vec(0usize..=8, 1..20)
+}
+
+proptest! {
+    #[test]
+    fn emulation_matches_reference(timers in timers(), days in 0u32..=60) {
+        prop_assert_eq!(
+            day6::emulate_lanterfish_and_count(&timers, days),
+            simulate(&timers, days)
+        );
+    }
+
+    #[test]
+    fn part1_matches_reference(timers in timers()) {
+        let input = timers
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<String>>()
+            .join(",");
+
+        prop_assert_eq!(day6::part1(&input).unwrap(), simulate(&timers, 80));
+    }
+}
@@ -0,0 +1,25 @@
+use common::Rng;
+
+/// Generates `size` crab positions between 0 and 1999, like the puzzle.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let positions = (0..size)
+        .map(|_| rng.range(0..=1999).to_string())
+        .collect::<Vec<String>>();
+
+    positions.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(3, 500);
+
+        assert_eq!(input, generate(3, 500));
+        assert_eq!(crate::Day7::parse(&input).unwrap().len(), 500);
+    }
+}
@@ -0,0 +1,174 @@
+pub mod generator;
+
+use std::fmt::Display;
+
+use common::{lint, Lint, Location, Solution};
+
+pub const INPUT: &str = include_str!("../input/day7.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidPosition(Location),
+    Empty,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidPosition(location) => {
+                write!(f, "{}: invalid position `{}`", location, location.text)
+            }
+            ParseError::Empty => write!(f, "expected at least one crab"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The Treachery of Whales: finds the cheapest position to align the crabs.
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i32>;
+    type Error = ParseError;
+    type Answer1 = i64;
+    // A single crab can need around 2^63 fuel
+    type Answer2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let crabs = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .flat_map(|(index, line)| line.split(',').map(move |x| (index, line, x.trim())))
+            .map(|(index, line, x)| match x.parse() {
+                Ok(position) => Ok(position),
+                Err(_) => Err(ParseError::InvalidPosition(Location::new(index, line, x))),
+            })
+            .collect::<Result<Vec<i32>, ParseError>>()?;
+
+        if crabs.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(crabs)
+    }
+
+    fn part1(crabs: &Self::Input) -> i64 {
+        if crabs.is_empty() {
+            return 0;
+        }
+
+        // Moving away from the median moves more crabs away than towards it
+        let mut sorted = crabs.clone();
+        let middle = sorted.len() / 2;
+        let (_, &mut median, _) = sorted.select_nth_unstable(middle);
+
+        crabs
+            .iter()
+            .map(|&x| (x as i64 - median as i64).abs())
+            .sum()
+    }
+
+    fn part2(crabs: &Self::Input) -> i128 {
+        if crabs.is_empty() {
+            return 0;
+        }
+
+        let fuel_cost = |column: i64| {
+            crabs
+                .iter()
+                .map(|&x| {
+                    let n = (x as i64 - column).abs() as i128;
+
+                    (n * (n + 1)) / 2
+                })
+                .sum()
+        };
+
+        // The total cost is convex and lowest within half a column of the
+        // mean, so the cheapest column is one of those around it
+        let sum = crabs.iter().map(|&x| x as i64).sum::<i64>();
+        let mean = sum.div_euclid(crabs.len() as i64);
+        (mean - 1..=mean + 2)
+            .map(fuel_cost)
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        for (count, (index, line)) in lint::lines(input).enumerate() {
+            if count > 0 {
+                lints.push(Lint::new(
+                    Location::line(index, line),
+                    "expected all crab positions on the first line",
+                ));
+            }
+            for value in line.split(',').map(str::trim) {
+                if !matches!(value.parse::<i32>(), Ok(position) if position >= 0) {
+                    lints.push(Lint::new(
+                        Location::new(index, line, value),
+                        format!("expected a non-negative position, found `{}`", value),
+                    ));
+                }
+            }
+        }
+
+        lints
+    }
+}
+
+pub fn part1(input: &str) -> Result<i64, ParseError> {
+    Ok(Day7::part1(&Day7::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<i128, ParseError> {
+    Ok(Day7::part2(&Day7::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = part1("16,1,2,0,4, 2a").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 13: invalid position `2a`"
+        );
+    }
+
+    #[test]
+    fn test_invalid_crabs() {
+        assert_eq!(part1("\n").unwrap_err(), ParseError::Empty);
+        assert_eq!(
+            part2("1,2147483648").unwrap_err().to_string(),
+            "line 1, column 3: invalid position `2147483648`"
+        );
+    }
+
+    #[test]
+    fn test_large_fuel_costs() {
+        let crabs = ["0,9999"; 500].join(",");
+
+        assert_eq!(part1(&crabs).unwrap(), 4999500);
+        assert_eq!(part2(&crabs).unwrap(), 12500000000);
+
+        let crabs = ["-2147483648,2147483647"; 500].join(",");
+
+        assert_eq!(part1(&crabs).unwrap(), 2147483647500);
+        assert_eq!(part2(&crabs).unwrap(), 2305843009213693952000);
+    }
+
+    #[test]
+    fn test_lint() {
+        let lints = Day7::lint("16,1,-2,0\n");
+
+        assert_eq!(
+            lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+            ["line 1, column 6: expected a non-negative position, found `-2`"]
+        );
+    }
+}
@@ -0,0 +1,44 @@
+//! Cross-checks both parts by trying every position from the nearest to the
+//! furthest crab.
+
+use proptest::prelude::*;
+
+fn cheapest(crabs: &[i32], cost: impl Fn(i64) -> i64) -> i64 {
+    let nearest = *crabs.iter().min().unwrap();
+    let furthest = *crabs.iter().max().unwrap();
+    (nearest..=furthest)
+        .map(|target| {
+            crabs
+                .iter()
+                .map(|crab| cost((crab - target).abs() as i64))
+                .sum()
+        })
+        .min()
+        .unwrap()
+}
+
+fn input(crabs: &[i32]) -> String {
+    crabs
+        .iter()
+        .map(i32::to_string)
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn crabs() -> impl Strategy<Value = Vec<i32>> {
+    prop::collection::vec(-150i32..150, 1..50)
+}
+
+proptest! {
+    #[test]
+    fn part1_matches_reference(crabs in crabs()) {
+        prop_assert_eq!(day7::part1(&input(&crabs)).unwrap(), cheapest(&crabs, |n| n));
+    }
+
+    #[test]
+    fn part2_matches_reference(crabs in crabs()) {
+        let expected = cheapest(&crabs, |n| (1..=n).sum());
+
+        prop_assert_eq!(day7::part2(&input(&crabs)).unwrap(), expected as i128);
+    }
+}
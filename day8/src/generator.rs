@@ -0,0 +1,58 @@
+use common::Rng;
+
+/// Segments of the digits 0 to 9 on an unscrambled display.
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Generates `size` notes, each with its own scrambled wiring, the ten
+/// unique patterns in random order and four random output digits.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut wiring = "abcdefg".chars().collect::<Vec<char>>();
+        rng.shuffle(&mut wiring);
+
+        let mut signals = (0..10)
+            .map(|digit| scramble(&mut rng, &wiring, digit))
+            .collect::<Vec<String>>();
+        let digits = (0..4)
+            .map(|_| {
+                let digit = rng.below(10) as usize;
+                scramble(&mut rng, &wiring, digit)
+            })
+            .collect::<Vec<String>>();
+        rng.shuffle(&mut signals);
+
+        input += &format!("{} | {}\n", signals.join(" "), digits.join(" "));
+    }
+
+    input
+}
+
+/// The segments of `digit` sent through `wiring`, in random order.
+fn scramble(rng: &mut Rng, wiring: &[char], digit: usize) -> String {
+    let mut segments = DIGITS[digit]
+        .chars()
+        .map(|c| wiring[(c as u8 - b'a') as usize])
+        .collect::<Vec<char>>();
+    rng.shuffle(&mut segments);
+
+    segments.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(3, 100);
+
+        assert_eq!(input, generate(3, 100));
+        assert_eq!(crate::Day8::parse(&input).unwrap().len(), 100);
+    }
+}
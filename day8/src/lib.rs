@@ -0,0 +1,359 @@
+pub mod generator;
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use common::{lint, Lint, Location, Solution};
+
+pub const INPUT: &str = include_str!("../input/day8.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    MissingSeparator(Location),
+    InvalidPattern(Location),
+    WrongPatternCount { location: Location, expected: usize },
+    InvalidSignals(Location),
+    UnknownDigit(Location),
+}
+
+impl ParseError {
+    fn location(&self) -> &Location {
+        match self {
+            ParseError::MissingSeparator(location)
+            | ParseError::InvalidPattern(location)
+            | ParseError::WrongPatternCount { location, .. }
+            | ParseError::InvalidSignals(location)
+            | ParseError::UnknownDigit(location) => location,
+        }
+    }
+
+    fn message(&self) -> String {
+        let text = &self.location().text;
+        match self {
+            ParseError::MissingSeparator(_) => {
+                format!("expected `<signals> | <digits>`, found `{}`", text)
+            }
+            ParseError::InvalidPattern(_) => {
+                format!("expected distinct segments `a` to `g`, found `{}`", text)
+            }
+            ParseError::WrongPatternCount { expected, .. } => {
+                format!("expected {} patterns, found `{}`", expected, text)
+            }
+            ParseError::InvalidSignals(_) => {
+                format!("expected the patterns of the ten digits, found `{}`", text)
+            }
+            ParseError::UnknownDigit(_) => {
+                format!("expected one of the signal patterns, found `{}`", text)
+            }
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.location(), self.message())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One entry of the notes: the ten unique signal patterns and the four
+/// digits of the output value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Note {
+    pub signals: Vec<String>,
+    pub digits: Vec<String>,
+}
+
+/// Seven Segment Search: decodes the scrambled displays.
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Note>;
+    type Error = ParseError;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| parse_note(index, line))
+            .collect()
+    }
+
+    fn part1(notes: &Self::Input) -> usize {
+        notes
+            .iter()
+            .flat_map(|note| note.digits.iter())
+            .filter(|x| matches!(x.chars().count(), 2 | 4 | 3 | 7))
+            .count()
+    }
+
+    fn part2(notes: &Self::Input) -> usize {
+        notes.iter().fold(0, |sum, note| {
+            let signal_pattern = note.signals.iter().map(String::as_str).collect();
+            let digits_with_sorted_chars = note
+                .digits
+                .iter()
+                .map(|d| {
+                    let mut sorted_chars = d.chars().collect::<Vec<char>>();
+                    sorted_chars.sort();
+                    sorted_chars.into_iter().collect::<String>()
+                })
+                .collect::<Vec<String>>();
+            let pattern = find_signal_pattern(signal_pattern);
+
+            sum + digits_with_sorted_chars.iter().rev().enumerate().fold(
+                0,
+                |line_sum, (i, digit)| {
+                    let digit = pattern[digit];
+                    line_sum + digit * 10usize.pow(i as u32)
+                },
+            )
+        })
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        let mut errors = Vec::new();
+        for (index, line) in lint::lines(input) {
+            check_note(index, line, &mut errors);
+        }
+
+        errors
+            .iter()
+            .map(|error| Lint::new(error.location().clone(), error.message()))
+            .collect()
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(Day8::part1(&Day8::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(Day8::part2(&Day8::parse(input)?))
+}
+
+/// Parses a single `<signals> | <digits>` line.
+pub fn parse_note(index: usize, line: &str) -> Result<Note, ParseError> {
+    let mut errors = Vec::new();
+    match check_note(index, line, &mut errors) {
+        Some(note) => Ok(note),
+        None => Err(errors.swap_remove(0)),
+    }
+}
+
+/// Checks a `<signals> | <digits>` line, adding every way it breaks the
+/// format to `errors`, and returns its note if there are none.
+fn check_note(index: usize, line: &str, errors: &mut Vec<ParseError>) -> Option<Note> {
+    let Some((signals, digits)) = line.trim().split_once(" | ") else {
+        errors.push(ParseError::MissingSeparator(Location::line(index, line)));
+        return None;
+    };
+    let found = errors.len();
+
+    let signal_patterns = check_patterns(index, line, signals, 10, errors);
+    let signals_valid = errors.len() == found;
+    let digit_patterns = check_patterns(index, line, digits, 4, errors);
+
+    // Decoding relies on every digit having its own pattern, told apart by
+    // the number of segments lit, which can only be checked on ten valid
+    // patterns
+    if !signals_valid {
+        return None;
+    }
+    let sorted = |pattern: &str| {
+        let mut segments = pattern.chars().collect::<Vec<char>>();
+        segments.sort_unstable();
+        segments
+    };
+    let mut known = signal_patterns
+        .iter()
+        .map(|x| sorted(x))
+        .collect::<Vec<_>>();
+    known.sort_unstable_by_key(|x| (x.len(), x.clone()));
+    known.dedup();
+    if known
+        .iter()
+        .map(Vec::len)
+        .ne([2, 3, 4, 5, 5, 5, 6, 6, 6, 7])
+    {
+        errors.push(ParseError::InvalidSignals(Location::new(
+            index, line, signals,
+        )));
+        return None;
+    }
+    for digit in &digit_patterns {
+        if !known.contains(&sorted(digit)) {
+            errors.push(ParseError::UnknownDigit(Location::new(index, line, digit)));
+        }
+    }
+
+    (errors.len() == found).then(|| Note {
+        signals: signal_patterns.iter().map(|x| x.to_string()).collect(),
+        digits: digit_patterns.iter().map(|x| x.to_string()).collect(),
+    })
+}
+
+/// The patterns in `section` of the line that are made of distinct segments
+/// `a` to `g`, adding the others and a wrong count to `errors`.
+fn check_patterns<'a>(
+    index: usize,
+    line: &'a str,
+    section: &'a str,
+    expected: usize,
+    errors: &mut Vec<ParseError>,
+) -> Vec<&'a str> {
+    let mut count = 0;
+    let patterns = section
+        .split_whitespace()
+        .inspect(|_| count += 1)
+        .filter(|pattern| {
+            let mut segments = pattern.chars().collect::<Vec<char>>();
+            segments.sort_unstable();
+            segments.dedup();
+            let valid =
+                segments.len() == pattern.len() && segments.iter().all(|c| ('a'..='g').contains(c));
+            if !valid {
+                errors.push(ParseError::InvalidPattern(Location::new(
+                    index, line, pattern,
+                )));
+            }
+            valid
+        })
+        .collect::<Vec<&str>>();
+
+    if count != expected {
+        errors.push(ParseError::WrongPatternCount {
+            location: Location::new(index, line, section),
+            expected,
+        });
+    }
+
+    patterns
+}
+
+/// Maps every signal pattern, with its segments sorted, to its digit.
+pub fn find_signal_pattern(signal: Vec<&str>) -> HashMap<String, usize> {
+    let mut sorted_signals = signal
+        .iter()
+        .map(|x| {
+            let mut vec = x.trim().chars().collect::<Vec<char>>();
+            vec.sort();
+            vec.iter().collect::<String>()
+        })
+        .collect::<Vec<String>>();
+
+    sorted_signals.sort_by_key(|x1| x1.len());
+
+    let mut indices: [usize; 10] = [1, 7, 4, 0, 0, 0, 0, 0, 0, 8];
+    let one_signal = &sorted_signals[0];
+    let seven_signal = &sorted_signals[1];
+    let four_signal = &sorted_signals[2];
+
+    for i in 3..6 {
+        let signal = &sorted_signals[i];
+        let intersection_seven_count = intersection_count(signal, seven_signal);
+        let intersection_four_count = intersection_count(signal, four_signal);
+        let value = match (intersection_four_count, intersection_seven_count) {
+            (2, _) => 2,
+            (_, 3) => 3,
+            _ => 5,
+        };
+
+        indices[i] = value;
+    }
+
+    for i in 6..9 {
+        let signal = &sorted_signals[i];
+        let intersection_four_count = intersection_count(signal, four_signal);
+        let intersection_one_count = intersection_count(signal, one_signal);
+
+        let value = match (intersection_four_count, intersection_one_count) {
+            (4, _) => 9,
+            (_, 1) => 6,
+            _ => 0,
+        };
+
+        indices[i] = value;
+    }
+
+    sorted_signals
+        .into_iter()
+        .zip(indices.iter().copied())
+        .collect()
+}
+
+/// Number of segments two patterns have in common.
+pub fn intersection_count(str1: &str, str2: &str) -> usize {
+    let str1: HashSet<char> = str1.chars().collect();
+    let str2: HashSet<char> = str2.chars().collect();
+
+    str1.intersection(&str2).count()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input =
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadh cdfeb cdbaf";
+
+        let error = part2(input).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 68: expected distinct segments `a` to `g`, found `fcadh`"
+        );
+    }
+
+    #[test]
+    fn test_undecodable_note() {
+        let error = part2("ab ab abc abcd abcde abcdf abcdg abcdef abcdeg abcdefg | ab ab ab ab")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected the patterns of the ten digits, found `ab ab abc abcd abcde abcdf abcdg abcdef abcdeg abcdefg`"
+        );
+
+        let error = part2(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcad cdfeb cdbaf",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 68: expected one of the signal patterns, found `fcad`"
+        );
+    }
+    #[test]
+    fn test_lint() {
+        let input =
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf
+acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab cdfeb fcadb cdfeb cdbaf
+acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb | cdfeb fcadb cdfeb cdbaf
+acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb xb | cdfeb fcadb cdfeb
+acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb abc cdfeb gf";
+
+        assert_eq!(
+            Day8::lint(input)
+                .iter()
+                .map(Lint::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "line 2, column 1: expected `<signals> | <digits>`, found `acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab cdfeb fcadb cdfeb cdbaf`",
+                "line 3, column 1: expected 10 patterns, found `acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb`",
+                "line 4, column 57: expected distinct segments `a` to `g`, found `xb`",
+                "line 4, column 62: expected 4 patterns, found `cdfeb fcadb cdfeb`",
+                "line 5, column 68: expected one of the signal patterns, found `abc`",
+                "line 5, column 78: expected one of the signal patterns, found `gf`",
+            ]
+        );
+    }
+}
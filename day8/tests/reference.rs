@@ -0,0 +1,97 @@
+//! Cross-checks both parts by decoding every entry with the one wiring, out
+//! of all 5040, that turns its ten patterns into the ten digits.
+
+use proptest::prelude::*;
+
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// The ten signal patterns and four output patterns of one display, wired
+/// with a random permutation of the segments.
+fn entry() -> impl Strategy<Value = (Vec<String>, Vec<String>)> {
+    let wiring = Just("abcdefg".chars().collect::<Vec<char>>()).prop_shuffle();
+    let order = Just((0..10).collect::<Vec<usize>>()).prop_shuffle();
+    let outputs = prop::collection::vec(0usize..10, 4);
+    (wiring, order, outputs).prop_map(|(wiring, order, outputs)| {
+        let scramble = |digit: usize| {
+            DIGITS[digit]
+                .chars()
+                .map(|segment| wiring[(segment as u8 - b'a') as usize])
+                .collect::<String>()
+        };
+        let signals = order.into_iter().map(scramble).collect();
+        let digits = outputs.into_iter().map(scramble).collect();
+        (signals, digits)
+    })
+}
+
+fn input(entries: &[(Vec<String>, Vec<String>)]) -> String {
+    entries
+        .iter()
+        .map(|(signals, digits)| format!("{} | {}\n", signals.join(" "), digits.join(" ")))
+        .collect()
+}
+
+fn permutations(items: Vec<char>) -> Vec<Vec<char>> {
+    if items.len() <= 1 {
+        return vec![items];
+    }
+    let mut all = Vec::new();
+    for i in 0..items.len() {
+        let mut rest = items.clone();
+        let first = rest.remove(i);
+        for mut permutation in permutations(rest) {
+            permutation.insert(0, first);
+            all.push(permutation);
+        }
+    }
+    all
+}
+
+/// The digit shown by `pattern` once unscrambled with `wiring`.
+fn digit(wiring: &[char], pattern: &str) -> Option<usize> {
+    let mut segments = pattern
+        .chars()
+        .map(|c| (b'a' + wiring.iter().position(|w| *w == c).unwrap() as u8) as char)
+        .collect::<Vec<char>>();
+    segments.sort_unstable();
+    let segments = segments.into_iter().collect::<String>();
+    DIGITS.iter().position(|digit| *digit == segments)
+}
+
+fn decode((signals, digits): &(Vec<String>, Vec<String>)) -> Vec<usize> {
+    let wiring = permutations("abcdefg".chars().collect())
+        .into_iter()
+        .find(|wiring| signals.iter().all(|signal| digit(wiring, signal).is_some()))
+        .unwrap();
+    digits
+        .iter()
+        .map(|pattern| digit(&wiring, pattern).unwrap())
+        .collect()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn part1_matches_reference(entries in prop::collection::vec(entry(), 1..4)) {
+        let expected = entries
+            .iter()
+            .flat_map(decode)
+            .filter(|digit| [1, 4, 7, 8].contains(digit))
+            .count();
+
+        prop_assert_eq!(day8::part1(&input(&entries)).unwrap(), expected);
+    }
+
+    #[test]
+    fn part2_matches_reference(entries in prop::collection::vec(entry(), 1..4)) {
+        let expected = entries
+            .iter()
+            .map(|entry| decode(entry).iter().fold(0, |value, digit| value * 10 + digit))
+            .sum::<usize>();
+
+        prop_assert_eq!(day8::part2(&input(&entries)).unwrap(), expected);
+    }
+}
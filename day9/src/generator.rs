@@ -0,0 +1,70 @@
+use common::Rng;
+
+/// Generates a `size` by `size` height map. The map is cut into rectangular
+/// basins of 3 to 10 cells per side, walled off by rows and columns of 9s,
+/// and each basin slopes up from a single low point so basins stay small
+/// like in the puzzle.
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let rows = cuts(&mut rng, size);
+    let columns = cuts(&mut rng, size);
+    let low_points = |rng: &mut Rng, cuts: &[(usize, usize)]| {
+        cuts.iter()
+            .map(|(start, end)| rng.range(*start as i64..=*end as i64 - 1) as usize)
+            .collect::<Vec<usize>>()
+    };
+    let low_rows = low_points(&mut rng, &rows);
+    let low_columns = low_points(&mut rng, &columns);
+
+    let mut input = String::new();
+    for y in 0..size {
+        for x in 0..size {
+            let row = rows
+                .iter()
+                .position(|(start, end)| (*start..*end).contains(&y));
+            let column = columns
+                .iter()
+                .position(|(start, end)| (*start..*end).contains(&x));
+
+            let height = match (row, column) {
+                (Some(row), Some(column)) => {
+                    (low_rows[row].abs_diff(y) + low_columns[column].abs_diff(x)).min(8)
+                }
+                _ => 9,
+            };
+            input += &height.to_string();
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+/// Splits `0..size` into basin ranges separated by single wall cells.
+fn cuts(rng: &mut Rng, size: usize) -> Vec<(usize, usize)> {
+    let mut cuts = Vec::new();
+    let mut start = 0;
+
+    while start < size {
+        let end = (start + rng.range(3..=10) as usize).min(size);
+        cuts.push((start, end));
+        start = end + 1;
+    }
+
+    cuts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(3, 50);
+
+        assert_eq!(input, generate(3, 50));
+        let map = crate::Day9::parse(&input).unwrap();
+        assert_eq!((map.width(), map.height()), (50, 50));
+    }
+}
@@ -0,0 +1,230 @@
+pub mod generator;
+
+use std::{fmt::Display, str::FromStr};
+
+use common::{grid::GridError, lint, Grid, Lint, Location, Solution};
+
+pub use common::Point;
+
+pub const INPUT: &str = include_str!("../input/day9.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidHeight(Location),
+    RaggedRow { location: Location, expected: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "the height map is empty"),
+            ParseError::InvalidHeight(location) => {
+                write!(f, "{}: invalid height `{}`", location, location.text)
+            }
+            ParseError::RaggedRow { location, expected } => write!(
+                f,
+                "{}: expected {} heights like the first row, found `{}`",
+                location, expected, location.text
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<GridError> for ParseError {
+    fn from(error: GridError) -> Self {
+        match error {
+            GridError::Empty => ParseError::Empty,
+            GridError::InvalidCell(location) => ParseError::InvalidHeight(location),
+            GridError::RaggedRow { location, expected } => {
+                ParseError::RaggedRow { location, expected }
+            }
+        }
+    }
+}
+
+/// The height map, with the points visited while exploring basins.
+#[derive(Clone)]
+pub struct Map {
+    heights: Grid<u32>,
+    visited: Grid<bool>,
+}
+
+impl Map {
+    pub fn heights(&self) -> &Grid<u32> {
+        &self.heights
+    }
+
+    pub fn width(&self) -> usize {
+        self.heights.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.heights.height()
+    }
+
+    pub fn visit(&mut self, point: Point) {
+        self.visited[point] = true;
+    }
+
+    pub fn has_visited(&self, point: Point) -> bool {
+        self.visited[point]
+    }
+
+    pub fn value(&self, point: Point) -> u32 {
+        self.heights[point]
+    }
+
+    pub fn inside_map(&self, point: &Point) -> bool {
+        self.heights.contains(*point)
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let heights = Grid::parse(s, |c| c.to_digit(10))?;
+        let visited = Grid::new(heights.width(), heights.height(), false);
+
+        Ok(Self { heights, visited })
+    }
+}
+
+/// Smoke Basin: finds the low points and the largest basins.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Map;
+    type Error = ParseError;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Map::from_str(input)
+    }
+
+    fn part1(height_map: &Self::Input) -> u32 {
+        let lowest_points = find_lowest_points(height_map);
+
+        lowest_points.iter().map(|(x, _)| x + 1).sum()
+    }
+
+    fn part2(height_map: &Self::Input) -> u64 {
+        let mut height_map = height_map.clone();
+        let lowest_points = find_lowest_points(&height_map);
+
+        let mut basins: Vec<u32> = lowest_points
+            .iter()
+            .map(|(_, point)| find_basin_sum(&mut height_map, *point))
+            .collect();
+
+        basins.sort_by(|a, b| b.cmp(a));
+
+        basins
+            .into_iter()
+            .take(3)
+            .map(u64::from)
+            .reduce(|mul, val| mul * val)
+            // A map without low points has no basins
+            .unwrap_or(0)
+    }
+
+    fn lint(input: &str) -> Vec<Lint> {
+        lint::grid(input, |c| c.is_ascii_digit(), "a height from 0 to 9")
+    }
+}
+
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    Ok(Day9::part1(&Day9::parse(input)?))
+}
+
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    Ok(Day9::part2(&Day9::parse(input)?))
+}
+
+/// Size of the unvisited basin containing `point`, marking it visited.
+pub fn find_basin_sum(map: &mut Map, point: Point) -> u32 {
+    if !map.inside_map(&point) || map.has_visited(point) || map.value(point) == 9 {
+        return 0;
+    }
+
+    // Points are marked when pushed so none is pushed twice
+    map.visit(point);
+    let mut stack = vec![point];
+    let mut size = 0;
+    while let Some(point) = stack.pop() {
+        size += 1;
+        for neighbour in map.heights.neighbours4(point) {
+            if !map.visited[neighbour] && map.heights[neighbour] != 9 {
+                map.visited[neighbour] = true;
+                stack.push(neighbour);
+            }
+        }
+    }
+
+    size
+}
+
+/// Every point lower than all of its neighbours, with its height.
+pub fn find_lowest_points(height_map: &Map) -> Vec<(u32, Point)> {
+    let heights = height_map.heights();
+
+    heights
+        .iter()
+        .filter(|(point, height)| {
+            heights
+                .neighbours4(*point)
+                .all(|neighbour| **height < heights[neighbour])
+        })
+        .map(|(point, height)| (*height, point))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "2199943210
+        398789492
+        9856789892";
+        let error = part1(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 9: expected 10 heights like the first row, found `398789492`"
+        );
+    }
+
+    #[test]
+    fn test_no_low_points() {
+        assert_eq!(part1("55\n55").unwrap(), 0);
+        assert_eq!(part2("55\n55").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_large_basin() {
+        let mut input = format!("{}\n", "1".repeat(400)).repeat(400);
+        input.replace_range(0..1, "0");
+        let mut map = Day9::parse(&input).unwrap();
+
+        assert_eq!(find_basin_sum(&mut map, Point(0, 0)), 160_000);
+        assert_eq!(find_basin_sum(&mut map, Point(5, 5)), 0);
+    }
+
+    #[test]
+    fn test_lint() {
+        let lints = Day9::lint("2199943210\n398789492\n98567a9892\n");
+
+        assert_eq!(
+            lints.iter().map(Lint::to_string).collect::<Vec<_>>(),
+            [
+                "line 2, column 1: expected 10 cells like the first row, found 9",
+                "line 3, column 6: expected a height from 0 to 9, found `a`",
+            ]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! Cross-checks part 1 on arbitrary height maps and part 2, which assumes
+//! every basin flows to a single low point, on generated ones.
+
+use proptest::prelude::*;
+
+fn heights() -> impl Strategy<Value = Vec<Vec<u32>>> {
+    (1usize..8, 1usize..8).prop_flat_map(|(width, height)| {
+        prop::collection::vec(prop::collection::vec(0u32..10, width), height)
+    })
+}
+
+fn input(heights: &[Vec<u32>]) -> String {
+    heights
+        .iter()
+        .map(|row| row.iter().map(u32::to_string).collect::<String>() + "\n")
+        .collect()
+}
+
+fn parse(input: &str) -> Vec<Vec<u32>> {
+    input
+        .lines()
+        .map(|row| row.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect()
+}
+
+fn neighbours(heights: &[Vec<u32>], x: usize, y: usize) -> Vec<(usize, usize)> {
+    let mut neighbours = Vec::new();
+    if x > 0 {
+        neighbours.push((x - 1, y));
+    }
+    if y > 0 {
+        neighbours.push((x, y - 1));
+    }
+    if x + 1 < heights[y].len() {
+        neighbours.push((x + 1, y));
+    }
+    if y + 1 < heights.len() {
+        neighbours.push((x, y + 1));
+    }
+    neighbours
+}
+
+/// Sizes of the areas of heights below 9, found by flood filling.
+fn basins(heights: &[Vec<u32>]) -> Vec<u32> {
+    let mut seen = vec![vec![false; heights[0].len()]; heights.len()];
+    let mut sizes = Vec::new();
+    for y in 0..heights.len() {
+        for x in 0..heights[y].len() {
+            if seen[y][x] || heights[y][x] == 9 {
+                continue;
+            }
+            let (mut size, mut todo) = (0, vec![(x, y)]);
+            seen[y][x] = true;
+            while let Some((x, y)) = todo.pop() {
+                size += 1;
+                for (nx, ny) in neighbours(heights, x, y) {
+                    if !seen[ny][nx] && heights[ny][nx] != 9 {
+                        seen[ny][nx] = true;
+                        todo.push((nx, ny));
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+    }
+    sizes
+}
+
+proptest! {
+    #[test]
+    fn part1_matches_reference(heights in heights()) {
+        let mut risk = 0;
+        for y in 0..heights.len() {
+            for x in 0..heights[y].len() {
+                let height = heights[y][x];
+                if neighbours(&heights, x, y).iter().all(|&(nx, ny)| height < heights[ny][nx]) {
+                    risk += height + 1;
+                }
+            }
+        }
+
+        prop_assert_eq!(day9::part1(&input(&heights)).unwrap(), risk);
+    }
+
+    #[test]
+    fn part2_matches_reference(seed in any::<u64>(), size in 3usize..30) {
+        let input = day9::generator::generate(seed, size);
+        let mut basins = basins(&parse(&input));
+        basins.sort_unstable_by(|a, b| b.cmp(a));
+
+        prop_assert_eq!(day9::part2(&input).unwrap(), basins.iter().take(3).map(|&x| x as u64).product::<u64>());
+    }
+}
@@ -0,0 +1,32 @@
+#![no_main]
+
+use common::Solution;
+use day1::{plot, profile::Profile, stream, Day1, Depth};
+use libfuzzer_sys::fuzz_target;
+
+fn depths<T: Depth + std::fmt::Display>(depths: &[T], window: usize, lag: usize) {
+    day1::count_window_increases(depths, window, lag);
+    Profile::new(depths);
+    plot::ascii(depths, window, 80, 20);
+    plot::svg(depths, window);
+}
+
+fuzz_target!(|data: (usize, usize, &str)| {
+    let (window, lag, input) = data;
+    Day1::lint(input);
+    if let Ok(parsed) = Day1::parse(input) {
+        Day1::part1(&parsed);
+        Day1::part2(&parsed);
+        depths(&parsed, window, lag);
+    }
+    if let Ok(parsed) = day1::parse_lines::<i64>(input) {
+        depths(&parsed, window, lag);
+    }
+    if let Ok(parsed) = day1::parse_lines::<f64>(input) {
+        depths(&parsed, window, lag);
+    }
+
+    let _ = stream::count_window_increases::<u32, _>(input.as_bytes(), window, lag);
+    let _ = stream::count_window_increases::<i64, _>(input.as_bytes(), window, lag);
+    let _ = stream::count_window_increases::<f64, _>(input.as_bytes(), window, lag);
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use common::Solution;
+use day10::Day10;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day10::lint(input);
+    day10::check_lines(input);
+    if let Ok(parsed) = Day10::parse(input) {
+        Day10::part1(&parsed);
+        Day10::part2(&parsed);
+    }
+});
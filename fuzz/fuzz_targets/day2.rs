@@ -0,0 +1,14 @@
+#![no_main]
+
+use common::Solution;
+use day2::Day2;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day2::lint(input);
+    let _ = input.parse::<day2::Command>();
+    if let Ok(parsed) = Day2::parse(input) {
+        Day2::part1(&parsed).to_string();
+        Day2::part2(&parsed).to_string();
+    }
+});
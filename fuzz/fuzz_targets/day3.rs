@@ -0,0 +1,13 @@
+#![no_main]
+
+use common::Solution;
+use day3::Day3;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day3::lint(input);
+    if let Ok(parsed) = Day3::parse(input) {
+        Day3::part1(&parsed);
+        Day3::part2(&parsed);
+    }
+});
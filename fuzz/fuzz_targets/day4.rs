@@ -0,0 +1,13 @@
+#![no_main]
+
+use common::Solution;
+use day4::Day4;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day4::lint(input);
+    if let Ok(parsed) = Day4::parse(input) {
+        Day4::part1(&parsed);
+        Day4::part2(&parsed);
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use common::Solution;
+use day5::Day5;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day5::lint(input);
+    let _ = input.parse::<day5::Line>();
+    if let Ok(parsed) = Day5::parse(input) {
+        Day5::part1(&parsed);
+        Day5::part2(&parsed);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use common::Solution;
+use day6::Day6;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day6::lint(input);
+    if let Ok(parsed) = Day6::parse(input) {
+        Day6::part1(&parsed);
+        Day6::part2(&parsed);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use common::Solution;
+use day7::Day7;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day7::lint(input);
+    if let Ok(parsed) = Day7::parse(input) {
+        Day7::part1(&parsed);
+        Day7::part2(&parsed);
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use common::Solution;
+use day8::Day8;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day8::lint(input);
+    let _ = day8::parse_note(0, input);
+    if let Ok(parsed) = Day8::parse(input) {
+        Day8::part1(&parsed);
+        Day8::part2(&parsed);
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use common::Solution;
+use day9::Day9;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    Day9::lint(input);
+    let _ = input.parse::<day9::Map>();
+    if let Ok(parsed) = Day9::parse(input) {
+        Day9::part1(&parsed);
+        Day9::part2(&parsed);
+    }
+});